@@ -7,15 +7,19 @@ use crate::{
 
 use abcf::ToBytes;
 use abcf_sdk::providers::HttpGetProvider;
-use anyhow::Result;
+use anyhow::{bail, Result};
 use async_compat::Compat;
 use clap::{ArgGroup, Parser};
 use futures::executor::block_on;
-use libfindora::Address;
+use libfindora::{asset::derive_asset_type, supply::SupplyOpening, Address};
 use libfn::{
-    entity::{Define as EntityDefine, Entity, Issue as EntityIssue},
-    net::{owned_outputs, send_tx},
-    types::SecretKey,
+    entity::{
+        Burn as EntityBurn, ConfigureAsset as EntityConfigureAsset, Define as EntityDefine, Entity,
+        FreezeAddress as EntityFreezeAddress, Issue as EntityIssue, ProveSupply,
+        TransferOwnership as EntityTransferOwnership,
+    },
+    net::{self, owned_outputs, send_tx},
+    types::{Address as FnAddress, SecretKey},
     utils::open_outputs,
     Builder,
 };
@@ -36,6 +40,16 @@ enum SubCommand {
     Show(Show),
     /// Issue an asset on ledger
     Issue(Issue),
+    /// Transfer ownership of an asset to another address
+    TransferOwnership(TransferOwnership),
+    /// Update configuration of an asset
+    Configure(Configure),
+    /// Freeze an address from spending a freezable asset
+    Freeze(Freeze),
+    /// Unfreeze an address frozen before
+    Unfreeze(Freeze),
+    /// Destroy an amount of asset
+    Burn(Burn),
 }
 
 #[derive(Parser, Debug)]
@@ -70,6 +84,15 @@ struct Create {
     /// Custom name of the new asset
     #[clap(short, long)]
     name: Option<String>,
+    /// Ticker symbol of the new asset
+    #[clap(short = 'S', long)]
+    symbol: Option<String>,
+    /// Code to derive the AssetType with the wallet address, default to the name
+    #[clap(short = 'c', long, forbid_empty_values = true)]
+    code: Option<String>,
+    /// Owner can freeze addresses from spending the new asset
+    #[clap(short = 'F', long)]
+    is_freezable: bool,
 }
 
 #[derive(Parser, Debug)]
@@ -107,12 +130,97 @@ struct Issue {
     is_confidential_amount: bool,
 }
 
+#[derive(Parser, Debug)]
+#[clap(group(ArgGroup::new("from").required(true).args(&["from-address", "from-secret"])))]
+struct TransferOwnership {
+    /// To specific an address as the Findora wallet of current owner which is
+    /// 1. ETH compatible address (0x...)
+    /// 2. Findora addreess (fra...)
+    #[clap(short = 'f', long, value_name = "ADDRESS", forbid_empty_values = true)]
+    from_address: Option<String>,
+    /// To specific a plain-text input as the Findora wallet of current owner which is a base64-formatted secret
+    #[clap(short = 's', long, value_name = "SECRET", forbid_empty_values = true)]
+    from_secret: Option<String>,
+    /// To specific a plain-text input as the AssetType which is a base64-formatted string
+    #[clap(short = 't', long, forbid_empty_values = true)]
+    asset_type: String,
+    /// Address of new owner which is a
+    /// 1. ETH compatible address (0x...) or
+    /// 2. Findora addreess (fra...)
+    #[clap(short = 'o', long, required = true, forbid_empty_values = true)]
+    to_address: String,
+}
+
+#[derive(Parser, Debug)]
+#[clap(group(ArgGroup::new("from").required(true).args(&["from-address", "from-secret"])))]
+struct Configure {
+    /// To specific an address as the Findora wallet of owner which is
+    /// 1. ETH compatible address (0x...)
+    /// 2. Findora addreess (fra...)
+    #[clap(short = 'f', long, value_name = "ADDRESS", forbid_empty_values = true)]
+    from_address: Option<String>,
+    /// To specific a plain-text input as the Findora wallet of owner which is a base64-formatted secret
+    #[clap(short = 's', long, value_name = "SECRET", forbid_empty_values = true)]
+    from_secret: Option<String>,
+    /// To specific a plain-text input as the AssetType which is a base64-formatted string
+    #[clap(short = 't', long, forbid_empty_values = true)]
+    asset_type: String,
+    /// Is the asset transferable
+    #[clap(short = 'T', long)]
+    is_transferable: bool,
+}
+
+#[derive(Parser, Debug)]
+#[clap(group(ArgGroup::new("from").required(true).args(&["from-address", "from-secret"])))]
+struct Freeze {
+    /// To specific an address as the Findora wallet of owner which is
+    /// 1. ETH compatible address (0x...)
+    /// 2. Findora addreess (fra...)
+    #[clap(short = 'f', long, value_name = "ADDRESS", forbid_empty_values = true)]
+    from_address: Option<String>,
+    /// To specific a plain-text input as the Findora wallet of owner which is a base64-formatted secret
+    #[clap(short = 's', long, value_name = "SECRET", forbid_empty_values = true)]
+    from_secret: Option<String>,
+    /// To specific a plain-text input as the AssetType which is a base64-formatted string
+    #[clap(short = 't', long, forbid_empty_values = true)]
+    asset_type: String,
+    /// Address to freeze or unfreeze which is a
+    /// 1. ETH compatible address (0x...) or
+    /// 2. Findora addreess (fra...)
+    #[clap(short = 'o', long, required = true, forbid_empty_values = true)]
+    target_address: String,
+}
+
+#[derive(Parser, Debug)]
+#[clap(group(ArgGroup::new("from").required(true).args(&["from-address", "from-secret"])))]
+struct Burn {
+    /// To specific an address as the Findora wallet which is
+    /// 1. ETH compatible address (0x...)
+    /// 2. Findora addreess (fra...)
+    #[clap(short = 'f', long, value_name = "ADDRESS", forbid_empty_values = true)]
+    from_address: Option<String>,
+    /// To specific a plain-text input as the Findora wallet which is a base64-formatted secret
+    #[clap(short = 's', long, value_name = "SECRET", forbid_empty_values = true)]
+    from_secret: Option<String>,
+    /// To specific a plain-text input as the AssetType which is a base64-formatted string
+    #[clap(short = 't', long, forbid_empty_values = true)]
+    asset_type: String,
+    /// Amount of the asset to burn
+    #[clap(short = 'm', long, required = true, forbid_empty_values = true)]
+    amount: u64,
+}
+
 impl Command {
     pub fn execute(&self, home: &Path, addr: &str) -> Result<Box<dyn Display>> {
         match &self.subcmd {
             SubCommand::Create(cmd) => create(cmd, home, addr),
             SubCommand::Show(cmd) => show(cmd, home, addr),
             SubCommand::Issue(cmd) => issue(cmd, home, addr),
+            SubCommand::TransferOwnership(cmd) => transfer_ownership(cmd, home, addr),
+            SubCommand::Configure(cmd) => configure(cmd, home, addr),
+            SubCommand::Freeze(cmd) => freeze(cmd, home, addr, true),
+            SubCommand::Unfreeze(cmd) => freeze(cmd, home, addr, false),
+            SubCommand::Burn(cmd) => burn(cmd, home, addr),
         }
     }
 }
@@ -124,14 +232,27 @@ fn create(cmd: &Create, home: &Path, addr: &str) -> Result<Box<dyn Display>> {
         None => None,
     };
 
+    let code = match cmd.code.as_ref().or_else(|| cmd.name.as_ref()) {
+        Some(c) => c.as_bytes().to_vec(),
+        None => bail!("code or name is required to derive the AssetType"),
+    };
+    let owner = Address::from(secret.to_public().key);
+
     let mut asset = entry_asset::Asset::new();
+    asset.asset_type = derive_asset_type(&owner, &code);
     let define = Entity::Define(EntityDefine {
         maximum,
         // FIXME: force to use transferable = true
         // transferable: cmd.is_transferable,
         transferable: true,
+        freezable: cmd.is_freezable,
+        name: cmd.name.clone(),
+        symbol: cmd.symbol.clone(),
+        decimals: cmd.decimal_place,
+        memo: cmd.memo.clone(),
         keypair: secret.key.clone().into_keypair(),
         asset: asset.asset_type,
+        code,
     });
 
     let mut provider = HttpGetProvider::new(addr);
@@ -205,6 +326,28 @@ fn show(cmd: &Show, home: &Path, addr: &str) -> Result<Box<dyn Display>> {
             // remap the asset information if it can be found in the local file
             asset = a;
         }
+
+        // the maximum on chain is enforced, trust it rather than the local file
+        if let Some(supply) = block_on(Compat::new(net::asset::get_supply(
+            &mut provider,
+            &asset_type,
+        )))? {
+            asset.maximum = supply.maximum.map(|m| m.low_u64());
+        }
+
+        // metadata on chain is shared with other wallets, prefer it as well
+        if let Some(meta) = block_on(Compat::new(net::asset::get_metadata(
+            &mut provider,
+            &asset_type,
+        )))? {
+            asset.decimal_place = meta.decimals;
+            if meta.name.is_some() {
+                asset.name = meta.name;
+            }
+            if meta.memo.is_some() {
+                asset.memo = meta.memo;
+            }
+        }
         result.push((asset, Some(amount)));
     }
 
@@ -223,14 +366,54 @@ fn issue(cmd: &Issue, home: &Path, addr: &str) -> Result<Box<dyn Display>> {
         Err(_) => entry_asset::Asset::new_from_asset_type_base64(&cmd.asset_type)?,
     };
 
+    let mut provider = HttpGetProvider::new(addr);
+
+    // confidential supply under maximum is only known by the opening kept in the local file
+    let mut prove_supply = None;
+    if let Some(supply) = block_on(Compat::new(net::asset::get_supply(
+        &mut provider,
+        &asset.asset_type,
+    )))? {
+        if let Some(maximum) = supply.maximum {
+            if cmd.is_confidential_amount || supply.supply_commitment.is_some() {
+                let issued = supply.issued.low_u64();
+                let commitment = supply.supply_commitment.as_ref();
+
+                // last issue may be not on chain yet, or failed
+                let mut opening = None;
+                for o in [asset.pending_supply_opening, asset.supply_opening]
+                    .into_iter()
+                    .flatten()
+                {
+                    if o.is_opening(issued, commitment)? {
+                        opening = Some(o);
+                        break;
+                    }
+                }
+
+                let opening = match (commitment, opening) {
+                    (None, _) => SupplyOpening::new(issued),
+                    (Some(_), Some(o)) => o,
+                    (Some(_), None) => bail!("supply opening of this asset is not found"),
+                };
+                asset.supply_opening = Some(opening);
+                asset.pending_supply_opening = None;
+                prove_supply = Some(ProveSupply {
+                    opening,
+                    maximum: maximum.low_u64(),
+                });
+            }
+        }
+    }
+
     let issue = Entity::Issue(EntityIssue {
         amount: cmd.amount,
         asset_type: asset.asset_type,
         confidential_amount: cmd.is_confidential_amount,
         keypair: secret.key.into_keypair(),
+        prove_supply,
     });
 
-    let mut provider = HttpGetProvider::new(addr);
     let mut rng = ChaChaRng::from_entropy();
     let mut builder = Builder::default();
     block_on(Compat::new(builder.from_entities(
@@ -246,6 +429,10 @@ fn issue(cmd: &Issue, home: &Path, addr: &str) -> Result<Box<dyn Display>> {
 
     asset.is_issued = true;
     asset.is_confidential_amount = cmd.is_confidential_amount;
+    // keep the old opening until the new commitment is seen on chain
+    if let Some(opening) = builder.supply_openings.get(&asset.asset_type) {
+        asset.pending_supply_opening = Some(*opening);
+    }
     entry_asset::Assets::new(home)?.update(&asset)?;
 
     Ok(Box::new(display_asset::Display::new(
@@ -254,6 +441,129 @@ fn issue(cmd: &Issue, home: &Path, addr: &str) -> Result<Box<dyn Display>> {
     )))
 }
 
+fn transfer_ownership(
+    cmd: &TransferOwnership,
+    home: &Path,
+    addr: &str,
+) -> Result<Box<dyn Display>> {
+    let secret = get_secret(home, &cmd.from_address, &cmd.from_secret)?;
+    let mut asset = entry_asset::Asset::new_from_asset_type_base64(&cmd.asset_type)?;
+    let to_address = entry_wallet::detect_address(&cmd.to_address)?;
+
+    let transfer = Entity::TransferOwnership(EntityTransferOwnership {
+        keypair: secret.key.into_keypair(),
+        asset: asset.asset_type,
+        address: Address::from(FnAddress::from_base64(&to_address)?.address),
+    });
+
+    send_entities(addr, vec![transfer])?;
+
+    asset.address = FnAddress::from_base64(&to_address)?.to_eth()?;
+
+    Ok(Box::new(display_asset::Display::new(
+        display_asset::DisplayType::Update,
+        vec![(asset, None)],
+    )))
+}
+
+fn configure(cmd: &Configure, home: &Path, addr: &str) -> Result<Box<dyn Display>> {
+    let secret = get_secret(home, &cmd.from_address, &cmd.from_secret)?;
+    let address = secret.to_public().to_address()?.to_eth()?;
+    let mut assets = entry_asset::Assets::new(home)?;
+    let local = assets.read(&address, &cmd.asset_type).ok();
+    let mut asset = match &local {
+        Some(a) => a.clone(),
+        None => entry_asset::Asset::new_from_asset_type_base64(&cmd.asset_type)?,
+    };
+
+    let configure = Entity::ConfigureAsset(EntityConfigureAsset {
+        keypair: secret.key.into_keypair(),
+        asset: asset.asset_type,
+        transferable: cmd.is_transferable,
+    });
+
+    send_entities(addr, vec![configure])?;
+
+    asset.address = address;
+    asset.is_transferable = cmd.is_transferable;
+    if local.is_some() {
+        assets.update(&asset)?;
+    }
+
+    Ok(Box::new(display_asset::Display::new(
+        display_asset::DisplayType::Update,
+        vec![(asset, None)],
+    )))
+}
+
+fn freeze(cmd: &Freeze, home: &Path, addr: &str, frozen: bool) -> Result<Box<dyn Display>> {
+    let secret = get_secret(home, &cmd.from_address, &cmd.from_secret)?;
+    let mut asset = entry_asset::Asset::new_from_asset_type_base64(&cmd.asset_type)?;
+    let target = FnAddress::from_base64(&entry_wallet::detect_address(&cmd.target_address)?)?;
+
+    let freeze = Entity::FreezeAddress(EntityFreezeAddress {
+        keypair: secret.key.into_keypair(),
+        asset: asset.asset_type,
+        address: Address::from(target.address),
+        frozen,
+    });
+
+    send_entities(addr, vec![freeze])?;
+
+    asset.address = target.to_eth()?;
+
+    Ok(Box::new(display_asset::Display::new(
+        if frozen {
+            display_asset::DisplayType::Freeze
+        } else {
+            display_asset::DisplayType::Unfreeze
+        },
+        vec![(asset, None)],
+    )))
+}
+
+fn burn(cmd: &Burn, home: &Path, addr: &str) -> Result<Box<dyn Display>> {
+    let secret = get_secret(home, &cmd.from_address, &cmd.from_secret)?;
+    let address = secret.to_public().to_address()?.to_eth()?;
+    let mut asset = match entry_asset::Assets::new(home)?.read(&address, &cmd.asset_type) {
+        Ok(a) => a,
+        Err(_) => entry_asset::Asset::new_from_asset_type_base64(&cmd.asset_type)?,
+    };
+
+    let burn = Entity::Burn(EntityBurn {
+        keypair: secret.key.into_keypair(),
+        amount: cmd.amount,
+        asset_type: asset.asset_type,
+    });
+
+    send_entities(addr, vec![burn])?;
+
+    asset.address = address;
+
+    Ok(Box::new(display_asset::Display::new(
+        display_asset::DisplayType::Burn,
+        vec![(asset, Some(cmd.amount))],
+    )))
+}
+
+fn send_entities(addr: &str, entities: Vec<Entity>) -> Result<()> {
+    let mut provider = HttpGetProvider::new(addr);
+    let mut rng = ChaChaRng::from_entropy();
+    let mut builder = Builder::default();
+    block_on(Compat::new(builder.fetch_status(&mut provider)))?;
+    block_on(Compat::new(builder.from_entities(
+        &mut rng,
+        &mut provider,
+        entities,
+    )))?;
+    block_on(Compat::new(send_tx(
+        &mut provider,
+        // TODO: change back to ? when abcf error type is satisfying
+        builder.build(&mut rng)?.to_bytes().unwrap(),
+    )))?;
+    Ok(())
+}
+
 fn get_secret(home: &Path, addr: &Option<String>, secret: &Option<String>) -> Result<SecretKey> {
     if let Some(addr) = addr {
         Ok(SecretKey::from_base64(
@@ -288,6 +598,9 @@ mod tests {
                 decimal_place: 6,
                 maximum: None,
                 name: None,
+                symbol: None,
+                code: Some("TEST".to_string()),
+                is_freezable: false,
             }),
         };
 
@@ -328,4 +641,72 @@ mod tests {
         // should be connection refused error
         assert!(cmd.execute(home.path(), "127.0.0.1").is_err());
     }
+
+    #[test]
+    fn test_command_asset_execute_transfer_ownership() {
+        let home = TempDir::new("test_command_asset_execute_transfer_ownership").unwrap();
+        let cmd = Command {
+            subcmd: SubCommand::TransferOwnership(TransferOwnership {
+                from_address: Some("0xf8d1fa7c6a8af4a78f862cac72fe05de0e308117".to_string()),
+                from_secret: None,
+                asset_type: "1TYZSwkxQI6-q49vgFsCOuXaOjaHbhtEV2GyDoPglUU=".to_string(),
+                to_address: "0x283590e19dee343ea0a8f4ecec906d53308068b5".to_string(),
+            }),
+        };
+
+        // because we did not setup the findorad server
+        // should be connection refused error
+        assert!(cmd.execute(home.path(), "127.0.0.1").is_err());
+    }
+
+    #[test]
+    fn test_command_asset_execute_configure() {
+        let home = TempDir::new("test_command_asset_execute_configure").unwrap();
+        let cmd = Command {
+            subcmd: SubCommand::Configure(Configure {
+                from_address: Some("0xf8d1fa7c6a8af4a78f862cac72fe05de0e308117".to_string()),
+                from_secret: None,
+                asset_type: "1TYZSwkxQI6-q49vgFsCOuXaOjaHbhtEV2GyDoPglUU=".to_string(),
+                is_transferable: false,
+            }),
+        };
+
+        // because we did not setup the findorad server
+        // should be connection refused error
+        assert!(cmd.execute(home.path(), "127.0.0.1").is_err());
+    }
+
+    #[test]
+    fn test_command_asset_execute_freeze() {
+        let home = TempDir::new("test_command_asset_execute_freeze").unwrap();
+        let cmd = Command {
+            subcmd: SubCommand::Freeze(Freeze {
+                from_address: Some("0xf8d1fa7c6a8af4a78f862cac72fe05de0e308117".to_string()),
+                from_secret: None,
+                asset_type: "1TYZSwkxQI6-q49vgFsCOuXaOjaHbhtEV2GyDoPglUU=".to_string(),
+                target_address: "0x283590e19dee343ea0a8f4ecec906d53308068b5".to_string(),
+            }),
+        };
+
+        // because we did not setup the findorad server
+        // should be connection refused error
+        assert!(cmd.execute(home.path(), "127.0.0.1").is_err());
+    }
+
+    #[test]
+    fn test_command_asset_execute_burn() {
+        let home = TempDir::new("test_command_asset_execute_burn").unwrap();
+        let cmd = Command {
+            subcmd: SubCommand::Burn(Burn {
+                from_address: Some("0xf8d1fa7c6a8af4a78f862cac72fe05de0e308117".to_string()),
+                from_secret: None,
+                asset_type: "1TYZSwkxQI6-q49vgFsCOuXaOjaHbhtEV2GyDoPglUU=".to_string(),
+                amount: 99,
+            }),
+        };
+
+        // because we did not setup the findorad server
+        // should be connection refused error
+        assert!(cmd.execute(home.path(), "127.0.0.1").is_err());
+    }
 }
@@ -46,7 +46,7 @@ impl Opts {
             SubCommand::Delegate(c) => c.execute(config),
             SubCommand::Setup(c) => c.execute(&mut config),
             SubCommand::Transfer(c) => c.execute(&self.home, &config.node.address),
-            SubCommand::Wallet(c) => c.execute(&self.home),
+            SubCommand::Wallet(c) => c.execute(&self.home, &config.node.address),
         }
     }
 }
@@ -10,9 +10,11 @@ use anyhow::Result;
 use async_compat::Compat;
 use clap::{ArgGroup, Parser};
 use futures::executor::block_on;
+use libfindora::utxo::Lock;
 use libfn::{
     entity,
     types::{Address, SecretKey},
+    utils::CoinSelection,
     Builder,
 };
 use rand_chacha::rand_core::SeedableRng;
@@ -64,6 +66,31 @@ struct Send {
     /// Make the asset code confidential in the transaction
     #[clap(short = 'A', long)]
     is_confidential_asset: bool,
+    /// Lock the sent output until this block height
+    #[clap(long, conflicts_with = "lock-timestamp")]
+    lock_height: Option<i64>,
+    /// Lock the sent output until this unix timestamp in seconds
+    #[clap(long)]
+    lock_timestamp: Option<i64>,
+    /// Strategy to pick owned outputs as inputs
+    #[clap(long, default_value = "largest-first", possible_values = COIN_SELECTIONS)]
+    coin_selection: CoinSelection,
+}
+
+const COIN_SELECTIONS: &[&str] = &["all", "largest-first", "branch-and-bound", "random"];
+
+impl Send {
+    fn lock(&self) -> Option<Lock> {
+        to_lock(self.lock_height, self.lock_timestamp)
+    }
+}
+
+fn to_lock(height: Option<i64>, timestamp: Option<i64>) -> Option<Lock> {
+    match (height, timestamp) {
+        (Some(h), _) => Some(Lock::Height(h)),
+        (None, Some(t)) => Some(Lock::Timestamp(t)),
+        (None, None) => None,
+    }
 }
 
 #[derive(Parser, Debug)]
@@ -81,6 +108,9 @@ struct Batch {
     /// Name of the batch process will be executing
     #[clap(forbid_empty_values = true)]
     batch_name: String,
+    /// Strategy to pick owned outputs as inputs
+    #[clap(long, default_value = "largest-first", possible_values = COIN_SELECTIONS)]
+    coin_selection: CoinSelection,
 }
 
 #[derive(Parser, Debug)]
@@ -106,6 +136,7 @@ fn send(cmd: &Send, home: &Path, addr: &str) -> Result<Box<dyn Display>> {
 
     send_tx(
         addr,
+        cmd.coin_selection,
         vec![entity::Entity::Transfer(
             entity::Transfer::builder()
                 .from(&secret.to_base64()?)
@@ -115,6 +146,7 @@ fn send(cmd: &Send, home: &Path, addr: &str) -> Result<Box<dyn Display>> {
                 .asset_type(&cmd.asset_type)
                 .confidential_amount(cmd.is_confidential_amount)
                 .confidential_asset(cmd.is_confidential_asset)
+                .lock(cmd.lock())
                 .build()?,
         )],
     )?;
@@ -138,6 +170,8 @@ fn save(cmd: &Save, home: &Path) -> Result<Box<dyn Display>> {
         asset_type: cmd.request.asset_type.clone(),
         is_confidential_amount: cmd.request.is_confidential_amount,
         is_confidential_asset: cmd.request.is_confidential_asset,
+        lock_height: cmd.request.lock_height,
+        lock_timestamp: cmd.request.lock_timestamp,
     })?;
 
     Ok(Box::new(display_transfer::Display::from((
@@ -161,11 +195,12 @@ fn batch(cmd: &Batch, home: &Path, addr: &str) -> Result<Box<dyn Display>> {
                 .asset_type(&t.asset_type)
                 .confidential_amount(t.is_confidential_amount)
                 .confidential_asset(t.is_confidential_asset)
+                .lock(to_lock(t.lock_height, t.lock_timestamp))
                 .build()?,
         ));
     }
 
-    send_tx(addr, entities)?;
+    send_tx(addr, cmd.coin_selection, entities)?;
     tfs.delete(&cmd.batch_name)?;
 
     Ok(Box::new(display_transfer::Display::from((
@@ -217,10 +252,15 @@ fn show(cmd: &Show, home: &Path) -> Result<Box<dyn Display>> {
     }
 }
 
-fn send_tx(addr: &str, entities: Vec<entity::Entity>) -> Result<()> {
+fn send_tx(addr: &str, coin_selection: CoinSelection, entities: Vec<entity::Entity>) -> Result<()> {
     let mut prng = ChaChaRng::from_entropy();
     let mut provider = HttpGetProvider::new(addr);
-    let mut builder = Builder::default();
+    let mut builder = Builder {
+        coin_selection,
+        ..Default::default()
+    };
+
+    block_on(Compat::new(builder.fetch_status(&mut provider)))?;
 
     block_on(Compat::new(builder.from_entities(
         &mut prng,
@@ -266,6 +306,9 @@ mod tests {
                 to_address: "0x283590e19dee343ea0a8f4ecec906d53308068b5".to_string(),
                 is_confidential_amount: false,
                 is_confidential_asset: false,
+                lock_height: None,
+                lock_timestamp: None,
+                coin_selection: CoinSelection::default(),
             }),
         };
 
@@ -299,6 +342,9 @@ mod tests {
                     to_address: "0xf8d1fa7c6a8af4a78f862cac72fe05de0e308117".to_string(),
                     is_confidential_amount: false,
                     is_confidential_asset: false,
+                    lock_height: None,
+                    lock_timestamp: None,
+                    coin_selection: CoinSelection::default(),
                 },
             }),
         };
@@ -319,6 +365,7 @@ mod tests {
         let cmd = Command {
             subcmd: SubCommand::Batch(Batch {
                 batch_name: "test_command_transfer_execute_save_show_batch".to_string(),
+                coin_selection: CoinSelection::default(),
             }),
         };
         // because we did not setup the findorad server
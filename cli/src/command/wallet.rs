@@ -1,11 +1,22 @@
 use std::{fmt::Display, path::Path};
 
 use crate::display::wallet as display_wallet;
-use crate::entry::wallet as entry_wallet;
+use crate::entry::{asset as entry_asset, wallet as entry_wallet};
 
+use abcf::ToBytes;
+use abcf_sdk::providers::HttpGetProvider;
 use anyhow::{bail, Result};
+use async_compat::Compat;
 use clap::{ArgGroup, Parser};
-use libfn::types::Wallet;
+use futures::executor::block_on;
+use libfindora::asset::FRA;
+use libfn::{
+    entity::{Consolidate as EntityConsolidate, Entity},
+    net::send_tx,
+    types::{SecretKey, Wallet},
+    Builder,
+};
+use rand_chacha::{rand_core::SeedableRng, ChaChaRng};
 
 #[derive(Parser, Debug)]
 pub struct Command {
@@ -21,6 +32,8 @@ enum SubCommand {
     Create(Create),
     /// Delete a wallet
     Delete(Delete),
+    /// Merge small outputs of an asset into one output
+    Consolidate(Consolidate),
 }
 
 #[derive(Parser, Debug)]
@@ -61,14 +74,35 @@ struct Delete {
     from_secret: Option<String>,
 }
 
+#[derive(Parser, Debug)]
+#[clap(group(ArgGroup::new("from").required(true).args(&["from-address", "from-secret"])))]
+struct Consolidate {
+    /// To specific an address as the Findora wallet which is
+    /// 1. ETH compatible address (0x...)
+    /// 2. Findora addreess (fra...)
+    /// to merge its outputs
+    #[clap(short = 'f', long, value_name = "ADDRESS", forbid_empty_values = true)]
+    from_address: Option<String>,
+    /// To specific a plain-text input as the Findora wallet which is a base64-formatted secret to merge its outputs
+    #[clap(short = 's', long, value_name = "SECRET", forbid_empty_values = true)]
+    from_secret: Option<String>,
+    /// Asset Type to merge which is a base64-formatted string, FRA if not set
+    #[clap(short = 'a', long, forbid_empty_values = true)]
+    asset_type: Option<String>,
+    /// Max inputs of the transaction, include the one paying fee
+    #[clap(short = 'i', long, default_value_t = 32)]
+    max_inputs: usize,
+}
+
 impl Command {
-    pub fn execute(&self, home: &Path) -> Result<Box<dyn Display>> {
+    pub fn execute(&self, home: &Path, addr: &str) -> Result<Box<dyn Display>> {
         let mut wallets = entry_wallet::Wallets::new(home)?;
 
         match &self.subcmd {
             SubCommand::Show(cmd) => show(cmd, &wallets),
             SubCommand::Create(cmd) => create(cmd, &mut wallets),
             SubCommand::Delete(cmd) => delete(cmd, &mut wallets),
+            SubCommand::Consolidate(cmd) => consolidate(cmd, &wallets, addr),
         }
     }
 }
@@ -105,6 +139,46 @@ fn delete(cmd: &Delete, wallets: &mut entry_wallet::Wallets) -> Result<Box<dyn D
     ))))
 }
 
+fn consolidate(
+    cmd: &Consolidate,
+    wallets: &entry_wallet::Wallets,
+    addr: &str,
+) -> Result<Box<dyn Display>> {
+    let wallet = get_wallet(wallets, &cmd.from_address, &cmd.from_secret)?;
+    let secret = SecretKey::from_base64(&wallet.secret)?;
+
+    let asset_type = match &cmd.asset_type {
+        Some(a) => entry_asset::Asset::new_from_asset_type_base64(a)?.asset_type,
+        None => FRA.bare_asset_type,
+    };
+
+    let consolidate = Entity::Consolidate(EntityConsolidate {
+        keypair: secret.key.into_keypair(),
+        asset_type,
+        max_inputs: cmd.max_inputs,
+    });
+
+    let mut provider = HttpGetProvider::new(addr);
+    let mut rng = ChaChaRng::from_entropy();
+    let mut builder = Builder::default();
+    block_on(Compat::new(builder.fetch_status(&mut provider)))?;
+    block_on(Compat::new(builder.from_entities(
+        &mut rng,
+        &mut provider,
+        vec![consolidate],
+    )))?;
+    block_on(Compat::new(send_tx(
+        &mut provider,
+        // TODO: change back to ? when abcf error type is satisfying
+        builder.build(&mut rng)?.to_bytes().unwrap(),
+    )))?;
+
+    Ok(Box::new(display_wallet::Display::from((
+        wallet.to_eth_address()?,
+        display_wallet::DisplayType::Consolidate,
+    ))))
+}
+
 #[derive(Debug)]
 struct NoSourceError;
 impl std::fmt::Display for NoSourceError {
@@ -166,13 +240,13 @@ mod tests {
                 from_secret: None,
             }),
         };
-        assert!(cmd.execute(node_home.path()).is_ok());
+        assert!(cmd.execute(node_home.path(), "127.0.0.1").is_ok());
         cmd.subcmd = SubCommand::Show(Show {
             from_address: Some("some_address".to_string()),
             from_secret: None,
         });
         // because not found
-        assert!(cmd.execute(node_home.path()).is_err());
+        assert!(cmd.execute(node_home.path(), "127.0.0.1").is_err());
     }
 
     #[test]
@@ -184,7 +258,7 @@ mod tests {
                 name: None,
             }),
         };
-        assert!(cmd.execute(node_home.path()).is_ok());
+        assert!(cmd.execute(node_home.path(), "127.0.0.1").is_ok());
     }
 
     #[test]
@@ -197,6 +271,21 @@ mod tests {
             }),
         };
         // because the input address is EthereumAddressFormatError
-        assert!(cmd.execute(node_home.path()).is_err());
+        assert!(cmd.execute(node_home.path(), "127.0.0.1").is_err());
+    }
+
+    #[test]
+    fn test_command_wallet_execute_consolidate() {
+        let node_home = TempDir::new("test_command_wallet_execute_consolidate").unwrap();
+        let cmd = Command {
+            subcmd: SubCommand::Consolidate(Consolidate {
+                from_address: Some("some_address".to_string()),
+                from_secret: None,
+                asset_type: None,
+                max_inputs: 32,
+            }),
+        };
+        // because not found
+        assert!(cmd.execute(node_home.path(), "127.0.0.1").is_err());
     }
 }
@@ -28,6 +28,10 @@ pub enum DisplayType {
     Show,
     Create,
     Issue,
+    Update,
+    Freeze,
+    Unfreeze,
+    Burn,
 }
 
 impl Display {
@@ -179,6 +183,79 @@ Is Confidential Amount: {}
             style(asset_type).white(),
         )
     }
+
+    fn update(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.contents.is_empty() {
+            return Err(fmt::Error);
+        }
+
+        let address = self.fetcher(&self.contents[0].address)?;
+        let asset_type = self.fetcher(&self.contents[0].asset_type)?;
+        write!(
+            f,
+            "
+{} {}
+{} Owner Address:          {}
+{} Asset Type:             {}
+",
+            Emoji("✨", ":)"),
+            style("Success Updated").bold().green(),
+            Emoji("★ ", "* "),
+            style(address).white(),
+            Emoji("★ ", "* "),
+            style(asset_type).white(),
+        )
+    }
+
+    fn burn(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.contents.is_empty() {
+            return Err(fmt::Error);
+        }
+
+        let address = self.fetcher(&self.contents[0].address)?;
+        let asset_type = self.fetcher(&self.contents[0].asset_type)?;
+        let amount = self.fetcher(&self.contents[0].amount)?;
+        write!(
+            f,
+            "
+{} {}
+{} ETH Compatible Address: {}
+{} Asset Type:             {}
+{} Amount:                 {}
+",
+            Emoji("✨", ":)"),
+            style("Success Burned").bold().green(),
+            Emoji("★ ", "* "),
+            style(address).white(),
+            Emoji("★ ", "* "),
+            style(asset_type).white(),
+            Emoji("★ ", "* "),
+            style(amount).white(),
+        )
+    }
+
+    fn freeze(&self, f: &mut fmt::Formatter<'_>, title: &str) -> fmt::Result {
+        if self.contents.is_empty() {
+            return Err(fmt::Error);
+        }
+
+        let address = self.fetcher(&self.contents[0].address)?;
+        let asset_type = self.fetcher(&self.contents[0].asset_type)?;
+        write!(
+            f,
+            "
+{} {}
+{} Target Address:         {}
+{} Asset Type:             {}
+",
+            Emoji("✨", ":)"),
+            style(title).bold().green(),
+            Emoji("★ ", "* "),
+            style(address).white(),
+            Emoji("★ ", "* "),
+            style(asset_type).white(),
+        )
+    }
 }
 
 impl fmt::Display for Display {
@@ -191,6 +268,10 @@ impl fmt::Display for Display {
             DisplayType::Show => self.show(f),
             DisplayType::Create => self.create(f),
             DisplayType::Issue => self.issue(f),
+            DisplayType::Update => self.update(f),
+            DisplayType::Freeze => self.freeze(f, "Success Frozen"),
+            DisplayType::Unfreeze => self.freeze(f, "Success Unfrozen"),
+            DisplayType::Burn => self.burn(f),
         }
     }
 }
@@ -26,6 +26,7 @@ pub enum DisplayType {
     Show,
     Create,
     Delete,
+    Consolidate,
 }
 
 impl Display {
@@ -152,6 +153,25 @@ Mnemonic:
             style(addr).white()
         )
     }
+
+    fn consolidate(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.contents.is_empty() {
+            return Err(fmt::Error);
+        }
+
+        let addr = self.fetcher(&self.contents[0].eth_compatible_address)?;
+        write!(
+            f,
+            "
+{} {}
+{} Address: {}
+",
+            Emoji("✨", ":)"),
+            style("Success Consolidated").bold().green(),
+            Emoji("★ ", "* "),
+            style(addr).white()
+        )
+    }
 }
 
 impl fmt::Display for Display {
@@ -165,6 +185,7 @@ impl fmt::Display for Display {
             DisplayType::Show => self.show(f),
             DisplayType::Create => self.create(f),
             DisplayType::Delete => self.delete(f),
+            DisplayType::Consolidate => self.consolidate(f),
         }
     }
 }
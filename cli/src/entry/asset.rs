@@ -4,7 +4,10 @@ use std::{
 };
 
 use anyhow::{bail, Context, Result};
-use libfindora::asset::{AssetType, ASSET_TYPE_LENGTH, FRA};
+use libfindora::{
+    asset::{AssetType, ASSET_TYPE_LENGTH, FRA},
+    supply::SupplyOpening,
+};
 use rand_chacha::{rand_core::RngCore, rand_core::SeedableRng, ChaChaRng};
 use serde::{Deserialize, Serialize};
 
@@ -24,6 +27,12 @@ pub struct Asset {
     pub is_transferable: bool,
     pub is_issued: bool,
     pub is_confidential_amount: bool,
+    /// Opening of confidential supply, needed to issue under maximum
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub supply_opening: Option<SupplyOpening>,
+    /// Opening after last issue, replaces `supply_opening` once the issue is on chain
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pending_supply_opening: Option<SupplyOpening>,
 }
 
 impl Default for Asset {
@@ -42,6 +51,8 @@ impl Default for Asset {
             is_transferable: false,
             is_issued: false,
             is_confidential_amount: false,
+            supply_opening: None,
+            pending_supply_opening: None,
         }
     }
 }
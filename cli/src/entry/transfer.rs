@@ -19,6 +19,10 @@ pub struct Transfer {
     pub asset_type: String,
     pub is_confidential_amount: bool,
     pub is_confidential_asset: bool,
+    #[serde(default)]
+    pub lock_height: Option<i64>,
+    #[serde(default)]
+    pub lock_timestamp: Option<i64>,
 }
 
 pub struct Transfers {
@@ -95,6 +99,8 @@ mod tests {
             asset_type: "asset_type_t1_1 ".to_string(),
             is_confidential_amount: true,
             is_confidential_asset: true,
+            lock_height: None,
+            lock_timestamp: None,
         };
         let t1_2 = Transfer {
             name: "name_t1 ".to_string(),
@@ -105,6 +111,8 @@ mod tests {
             asset_type: "asset_type_t1_2 ".to_string(),
             is_confidential_amount: true,
             is_confidential_asset: true,
+            lock_height: None,
+            lock_timestamp: None,
         };
 
         transfers.create(&t1_1).unwrap();
@@ -124,6 +132,8 @@ mod tests {
             asset_type: "asset_type_t2 ".to_string(),
             is_confidential_amount: false,
             is_confidential_asset: false,
+            lock_height: None,
+            lock_timestamp: None,
         };
         transfers.create(&t2).unwrap();
         assert_eq!(vec![t2.clone()], transfers.read(&t2.name).unwrap());
@@ -1,32 +1,47 @@
-// use abcf_sdk::jsonrpc::Request;
-// // use abcf_sdk::providers::{Provider, WsProvider};
+use abcf_sdk::jsonrpc::Request;
+use abcf_sdk::providers::{Provider, WsProvider};
+use libfindora::Address;
+use primitive_types::H160;
 use ruc::*;
-// use serde_json::json;
-// use serde_json::Value;
-// use std::thread::park;
-// use tokio::runtime::Runtime;
+use serde_json::json;
+use serde_json::Value;
+use std::str::FromStr;
+use tokio::runtime::Runtime;
 
+/// Follow outputs sent and received by address.
+///
+/// Usage: `cargo run --example subscribe -- <0x address>`
 fn main() -> Result<()> {
-    // let rt = Runtime::new().unwrap();
-    // let mut provider = WsProvider::new();
-    // pub_key can be change from test case
-    //     let query = json!(["SendEvent.pub_key='DK5o6w6OkXk6soHvMToYfp0W/rIWuk9ODjukNEpUFKI='"]);
-    // let subscribe_req = Request::new_to_value("subscribe", query);
-    //
-    // rt.block_on(async {
-    //     let resp = provider
-    //         .request::<Value, String>("subscribe", &subscribe_req)
-    //         .await
-    //         .unwrap();
-    //     println!("{:?}", resp);
-    //
-    //     loop {
-    //         let r = provider.receive().await.unwrap();
-    //         println!("{:?}", r);
-    //     }
-    // });
-    //
-    // park();
-    //
-    Ok(())
+    let arg = std::env::args()
+        .nth(1)
+        .c(d!("usage: subscribe <0x address>"))?;
+    let address = Address::from(H160::from_str(&arg).c(d!())?);
+    let address = serde_json::to_value(&address).c(d!())?;
+    let address = address.as_str().c(d!())?;
+
+    let rt = Runtime::new().c(d!())?;
+    let mut provider = WsProvider::new();
+
+    rt.block_on(async {
+        for event in ["SendEvent", "RecvEvent"] {
+            let query = json!({
+                "query": format!("tm.event='Tx' AND {}.address='{}'", event, address),
+            });
+            let subscribe_req = Request::new_to_value("subscribe", query);
+
+            let resp = provider
+                .request::<Value, Value>("subscribe", &subscribe_req)
+                .await
+                .map_err(|e| eg!(format!("{:?}", e)))?;
+            println!("subscribe {}: {:?}", event, resp);
+        }
+
+        loop {
+            let r = provider
+                .receive()
+                .await
+                .map_err(|e| eg!(format!("{:?}", e)))?;
+            println!("{:?}", r);
+        }
+    })
 }
@@ -43,8 +43,14 @@ pub fn define_issue_fra() -> Transaction {
     let define_entry = Entity::Define(Define {
         maximum: Some(U256::from(1000 + 210_0000_0000 * FRA.units)),
         transferable: true,
+        freezable: false,
+        name: Some(String::from("Findora")),
+        symbol: Some(String::from("FRA")),
+        decimals: FRA.decimals,
+        memo: None,
         keypair: kp.clone(),
         asset: FRA.bare_asset_type,
+        code: Vec::new(),
     });
 
     let issue_entry = Entity::Issue(Issue {
@@ -52,6 +58,7 @@ pub fn define_issue_fra() -> Transaction {
         asset_type: FRA.bare_asset_type,
         confidential_amount: false,
         keypair: kp,
+        prove_supply: None,
     });
 
     let rt = tokio::runtime::Runtime::new().unwrap();
@@ -67,3 +74,60 @@ pub fn define_issue_fra() -> Transaction {
         builder.build(&mut prng).unwrap()
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use abcf::bs3::{backend::MemoryBackend, model::Map, MapStore, SnapshotableStorage};
+    use fm_asset::AssetInfo;
+    use libfindora::{asset::AssetType, Address};
+
+    type Store<T> = SnapshotableStorage<MemoryBackend, T>;
+
+    fn owner() -> Address {
+        let wallet = Wallet::from_mnemonic("dentist earth learn way nominee satisfy scorpion curious gate chapter draw river broom tenant empower ordinary grunt window horn balance stone marble flat found").unwrap();
+
+        Address::from(wallet.secret.key.into_keypair().get_pk())
+    }
+
+    #[test]
+    fn test_define_signers() {
+        let tx = define_issue_fra();
+        let tx = fm_asset::Transaction::try_from(&tx).unwrap();
+
+        assert_eq!(tx.define_asset.len(), 1);
+        assert!(tx.signers.contains(&owner()));
+    }
+
+    #[test]
+    fn test_genesis_define() {
+        let tx = define_issue_fra();
+        let tx = fm_asset::Transaction::try_from(&tx).unwrap();
+
+        let mut asset_infos: Store<Map<AssetType, AssetInfo>> =
+            SnapshotableStorage::new(Default::default(), MemoryBackend::new()).unwrap();
+
+        fm_asset::utils::check_define(&mut asset_infos, &tx.signers, true, &tx.define_asset)
+            .unwrap();
+        assert_eq!(
+            asset_infos
+                .get(&FRA.bare_asset_type)
+                .unwrap()
+                .unwrap()
+                .owner,
+            owner()
+        );
+
+        // FRA can only be defined in genesis.
+        let mut asset_infos: Store<Map<AssetType, AssetInfo>> =
+            SnapshotableStorage::new(Default::default(), MemoryBackend::new()).unwrap();
+
+        assert!(fm_asset::utils::check_define(
+            &mut asset_infos,
+            &tx.signers,
+            false,
+            &tx.define_asset
+        )
+        .is_err());
+    }
+}
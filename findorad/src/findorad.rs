@@ -12,6 +12,7 @@ use zei::setup::PublicParams;
 use fm_asset::AssetModule;
 use fm_coinbase::CoinbaseModule;
 use fm_fee::FeeModule;
+use fm_rewards::RewardsModule;
 use fm_staking::StakingModule;
 use fm_utxo::UtxoModule;
 
@@ -27,6 +28,9 @@ use crate::Result;
 pub struct FindoradManager {
     #[dependence(coinbase = "coinbase")]
     pub staking: StakingModule,
+    #[dependence(staking = "staking", coinbase = "coinbase")]
+    pub rewards: RewardsModule,
+    #[dependence(utxo = "utxo")]
     pub asset: AssetModule,
     #[dependence(utxo = "utxo")]
     pub evm: EvmModule,
@@ -41,6 +45,7 @@ type FindoradManagerWithSled = FindoradManager<SledBackend>;
 pub struct Findorad {
     node: abcf_node::Node<abcf::entry::Node<sha3::Sha3_512, FindoradManagerWithSled>>,
     staking_backend: sled::Db,
+    rewards_backend: sled::Db,
     coinbase_backend: sled::Db,
     asset_backend: sled::Db,
     evm_backend: sled::Db,
@@ -60,6 +65,8 @@ impl Findorad {
 
         let staking = StakingModule::new(BTreeMap::new());
 
+        let rewards = RewardsModule::new();
+
         let asset = AssetModule::new();
 
         let evm = EvmModule::new(fm_evm::evm::vicinity::Vicinity::mainnet());
@@ -72,13 +79,17 @@ impl Findorad {
 
         let prng = ChaChaRng::from_entropy();
 
-        let utxo = UtxoModule::new(params, prng);
+        let utxo = UtxoModule::new(params, prng, BTreeMap::new());
 
-        let manager = FindoradManager::<SledBackend>::new(staking, asset, evm, fee, coinbase, utxo);
+        let manager =
+            FindoradManager::<SledBackend>::new(staking, rewards, asset, evm, fee, coinbase, utxo);
 
         let staking_backend =
             bs3::backend::sled_db_open(Some(format!("{}/{}", prefix_path, "staking").as_str()))
                 .unwrap();
+        let rewards_backend =
+            bs3::backend::sled_db_open(Some(format!("{}/{}", prefix_path, "rewards").as_str()))
+                .unwrap();
         let coinbase_backend =
             bs3::backend::sled_db_open(Some(format!("{}/{}", prefix_path, "coinbase").as_str()))
                 .unwrap();
@@ -122,6 +133,46 @@ impl Findorad {
                     SledBackend::open_tree(&staking_backend, "validator_pubkey").unwrap(),
                 )
                 .unwrap(),
+                validator_profile: bs3::SnapshotableStorage::new(
+                    Default::default(),
+                    SledBackend::open_tree(&staking_backend, "validator_profile").unwrap(),
+                )
+                .unwrap(),
+                commission_epoch: bs3::SnapshotableStorage::new(
+                    Default::default(),
+                    SledBackend::open_tree(&staking_backend, "commission_epoch").unwrap(),
+                )
+                .unwrap(),
+                unbonding: bs3::SnapshotableStorage::new(
+                    Default::default(),
+                    SledBackend::open_tree(&staking_backend, "unbonding").unwrap(),
+                )
+                .unwrap(),
+                unbonding_count: bs3::SnapshotableStorage::new(
+                    Default::default(),
+                    SledBackend::open_tree(&staking_backend, "unbonding_count").unwrap(),
+                )
+                .unwrap(),
+                unbonding_queue: bs3::SnapshotableStorage::new(
+                    Default::default(),
+                    SledBackend::open_tree(&staking_backend, "unbonding_queue").unwrap(),
+                )
+                .unwrap(),
+                redelegations: bs3::SnapshotableStorage::new(
+                    Default::default(),
+                    SledBackend::open_tree(&staking_backend, "redelegations").unwrap(),
+                )
+                .unwrap(),
+                jailed: bs3::SnapshotableStorage::new(
+                    Default::default(),
+                    SledBackend::open_tree(&staking_backend, "jailed").unwrap(),
+                )
+                .unwrap(),
+                missed_blocks: bs3::SnapshotableStorage::new(
+                    Default::default(),
+                    SledBackend::open_tree(&staking_backend, "missed_blocks").unwrap(),
+                )
+                .unwrap(),
                 __marker_s: PhantomData,
                 __marker_d: PhantomData,
             },
@@ -131,6 +182,31 @@ impl Findorad {
                     SledBackend::open_tree(&asset_backend, "asset_infos").unwrap(),
                 )
                 .unwrap(),
+                issued: bs3::SnapshotableStorage::new(
+                    Default::default(),
+                    SledBackend::open_tree(&asset_backend, "issued").unwrap(),
+                )
+                .unwrap(),
+                supply_commitment: bs3::SnapshotableStorage::new(
+                    Default::default(),
+                    SledBackend::open_tree(&asset_backend, "supply_commitment").unwrap(),
+                )
+                .unwrap(),
+                burned: bs3::SnapshotableStorage::new(
+                    Default::default(),
+                    SledBackend::open_tree(&asset_backend, "burned").unwrap(),
+                )
+                .unwrap(),
+                frozen: bs3::SnapshotableStorage::new(
+                    Default::default(),
+                    SledBackend::open_tree(&asset_backend, "frozen").unwrap(),
+                )
+                .unwrap(),
+                frozen_count: bs3::SnapshotableStorage::new(
+                    Default::default(),
+                    SledBackend::open_tree(&asset_backend, "frozen_count").unwrap(),
+                )
+                .unwrap(),
                 __marker_s: PhantomData,
                 __marker_d: PhantomData,
             },
@@ -148,6 +224,20 @@ impl Findorad {
                 __marker_s: PhantomData,
                 __marker_d: PhantomData,
             },
+            rewards: abcf::Stateful::<RewardsModule<SledBackend, Sha3_512>> {
+                rewards: abcf::bs3::SnapshotableStorage::new(
+                    Default::default(),
+                    SledBackend::open_tree(&rewards_backend, "rewards").unwrap(),
+                )
+                .unwrap(),
+                minted: abcf::bs3::SnapshotableStorage::new(
+                    Default::default(),
+                    SledBackend::open_tree(&rewards_backend, "minted").unwrap(),
+                )
+                .unwrap(),
+                __marker_s: PhantomData,
+                __marker_d: PhantomData,
+            },
             fee: abcf::Stateful::<FeeModule<SledBackend, Sha3_512>> {
                 sf_value: abcf::bs3::SnapshotableStorage::new(
                     Default::default(),
@@ -163,6 +253,11 @@ impl Findorad {
                     SledBackend::open_tree(&coinbase_backend, "coinbase").unwrap(),
                 )
                 .unwrap(),
+                pending_slot: abcf::bs3::SnapshotableStorage::new(
+                    Default::default(),
+                    SledBackend::open_tree(&coinbase_backend, "pending_slot").unwrap(),
+                )
+                .unwrap(),
                 __marker_s: PhantomData,
                 __marker_d: PhantomData,
             },
@@ -172,6 +267,16 @@ impl Findorad {
                     SledBackend::open_tree(&utxo_backend, "output_set").unwrap(),
                 )
                 .unwrap(),
+                hash_locks: bs3::SnapshotableStorage::new(
+                    Default::default(),
+                    SledBackend::open_tree(&utxo_backend, "hash_locks").unwrap(),
+                )
+                .unwrap(),
+                last_block: bs3::SnapshotableStorage::new(
+                    Default::default(),
+                    SledBackend::open_tree(&utxo_backend, "last_block").unwrap(),
+                )
+                .unwrap(),
                 __marker_s: PhantomData,
                 __marker_d: PhantomData,
             },
@@ -184,13 +289,47 @@ impl Findorad {
                     SledBackend::open_tree(&staking_backend, "delegation_amount").unwrap(),
                 )
                 .unwrap(),
+                delegator_unbonding: bs3::SnapshotableStorage::new(
+                    Default::default(),
+                    SledBackend::open_tree(&staking_backend, "delegator_unbonding").unwrap(),
+                )
+                .unwrap(),
+                __marker_s: PhantomData,
+                __marker_d: PhantomData,
+            },
+            rewards: abcf::Stateless::<RewardsModule<SledBackend, Sha3_512>> {
+                sl_value: abcf::bs3::SnapshotableStorage::new(
+                    Default::default(),
+                    SledBackend::open_tree(&rewards_backend, "sl_value").unwrap(),
+                )
+                .unwrap(),
                 __marker_s: PhantomData,
                 __marker_d: PhantomData,
             },
             asset: abcf::Stateless::<AssetModule<SledBackend, Sha3_512>> {
-                sl_value: bs3::SnapshotableStorage::new(
+                asset_list: bs3::SnapshotableStorage::new(
+                    Default::default(),
+                    SledBackend::open_tree(&asset_backend, "asset_list").unwrap(),
+                )
+                .unwrap(),
+                asset_count: bs3::SnapshotableStorage::new(
+                    Default::default(),
+                    SledBackend::open_tree(&asset_backend, "asset_count").unwrap(),
+                )
+                .unwrap(),
+                owned_assets: bs3::SnapshotableStorage::new(
+                    Default::default(),
+                    SledBackend::open_tree(&asset_backend, "owned_assets").unwrap(),
+                )
+                .unwrap(),
+                owned_assets_count: bs3::SnapshotableStorage::new(
+                    Default::default(),
+                    SledBackend::open_tree(&asset_backend, "owned_assets_count").unwrap(),
+                )
+                .unwrap(),
+                owned_assets_index: bs3::SnapshotableStorage::new(
                     Default::default(),
-                    SledBackend::open_tree(&staking_backend, "sl_value").unwrap(),
+                    SledBackend::open_tree(&asset_backend, "owned_assets_index").unwrap(),
                 )
                 .unwrap(),
                 __marker_s: PhantomData,
@@ -216,6 +355,22 @@ impl Findorad {
             },
             utxo: abcf::Stateless::<UtxoModule<SledBackend, Sha3_512>> {
                 owned_outputs: abcf::bs3::SnapshotableStorage::new(
+                    Default::default(),
+                    SledBackend::open_tree(&utxo_backend, "owned_outputs_slot").unwrap(),
+                )
+                .unwrap(),
+                owned_outputs_count: abcf::bs3::SnapshotableStorage::new(
+                    Default::default(),
+                    SledBackend::open_tree(&utxo_backend, "owned_outputs_count").unwrap(),
+                )
+                .unwrap(),
+                owned_outputs_index: abcf::bs3::SnapshotableStorage::new(
+                    Default::default(),
+                    SledBackend::open_tree(&utxo_backend, "owned_outputs_index").unwrap(),
+                )
+                .unwrap(),
+                // Existing tree of old layout.
+                legacy_owned_outputs: abcf::bs3::SnapshotableStorage::new(
                     Default::default(),
                     SledBackend::open_tree(&utxo_backend, "owned_outputs").unwrap(),
                 )
@@ -245,6 +400,7 @@ impl Findorad {
         Self {
             node,
             staking_backend,
+            rewards_backend,
             utxo_backend,
             coinbase_backend,
             asset_backend,
@@ -277,6 +433,7 @@ impl Findorad {
         self.evm_backend.flush()?;
         self.asset_backend.flush()?;
         self.staking_backend.flush()?;
+        self.rewards_backend.flush()?;
         self.fee_backend.flush()?;
         Ok(())
     }
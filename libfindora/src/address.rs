@@ -1,6 +1,7 @@
 use digest::Digest;
 use primitive_types::{H160, H256};
 use serde::{Deserialize, Serialize};
+use sha3::Sha3_256;
 use zei::{serialization::ZeiFromToBytes, xfr::sig::XfrPublicKey};
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize, PartialOrd, Ord, Default)]
@@ -14,6 +15,24 @@ impl Address {
     pub fn is_blockhole(&self) -> bool {
         self.0.is_zero()
     }
+
+    /// Address of M-of-N multi-signature owner.
+    ///
+    /// Derived from threshold and public keys, the order of public keys doesn't matter.
+    pub fn from_multisig(threshold: u32, public_keys: &[XfrPublicKey]) -> Self {
+        let mut keys: Vec<Vec<u8>> = public_keys.iter().map(|k| k.zei_to_bytes()).collect();
+        keys.sort();
+
+        let mut hasher = Sha3_256::new();
+        hasher.update(b"multisig");
+        hasher.update(threshold.to_be_bytes());
+        for key in keys {
+            hasher.update(key);
+        }
+
+        let h256 = H256::from_slice(&hasher.finalize());
+        Self(H160::from(h256))
+    }
 }
 
 impl From<&[u8]> for Address {
@@ -31,7 +50,7 @@ impl From<H160> for Address {
 impl From<XfrPublicKey> for Address {
     fn from(public_key: XfrPublicKey) -> Self {
         let bytes = public_key.zei_to_bytes();
-        let result = sha3::Sha3_256::digest(bytes.as_slice());
+        let result = Sha3_256::digest(bytes.as_slice());
         let h256 = H256::from_slice(&result);
         Self(H160::from(h256))
     }
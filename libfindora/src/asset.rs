@@ -20,11 +20,45 @@ pub const FRA: FraAsset = FraAsset {
 
 pub type Amount = u64;
 
+use digest::Digest;
 use primitive_types::U256;
 use serde::{Deserialize, Serialize};
+use sha3::Sha3_256;
+
+use crate::Address;
+
+/// Asset type defined by owner with code.
+///
+/// Same owner and code always derive same asset type, and different owners
+/// can't derive asset type of each other.
+pub fn derive_asset_type(owner: &Address, code: &[u8]) -> AssetType {
+    let mut hasher = Sha3_256::new();
+    hasher.update(b"asset");
+    hasher.update(owner);
+    hasher.update(code);
+
+    let mut asset_type = [0u8; ASSET_TYPE_LENGTH];
+    asset_type.copy_from_slice(&hasher.finalize());
+    AssetType(asset_type)
+}
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct AssetMeta {
     pub maximum: Option<U256>,
     pub transferable: bool,
+    /// Owner can freeze addresses from spending this asset.
+    pub freezable: bool,
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+    /// Decimal places to render amount.
+    pub decimals: u8,
+    pub memo: Option<String>,
+    /// Chosen by owner to derive asset type, see `derive_asset_type`.
+    pub code: Vec<u8>,
+}
+
+/// Mutable configuration of defined asset, only owner can update it.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct AssetConfig {
+    pub transferable: bool,
 }
@@ -1,5 +1,9 @@
 use std::{array::TryFromSliceError, fmt::Display, num::TryFromIntError};
 
+use primitive_types::H512;
+
+use crate::Address;
+
 pub type Result<T> = core::result::Result<T, Error>;
 
 #[derive(Debug)]
@@ -14,6 +18,17 @@ pub enum Error {
     Unknown,
     MustBeNonConfidentialAsset,
     AlreadySign,
+    SignatureAddressMismatch(Address),
+    SignatureVerifyFailed(Address),
+    DuplicateSignature(Address),
+    TxidMismatch(H512, H512),
+    MultiSignatureThresholdError(Address),
+    MultiSignatureAddressMismatch(Address),
+    MultiSignatureDuplicateKey(Address),
+    MultiSignatureNotEnough(Address, usize, usize),
+    InvalidCommitment,
+    SupplyExceedMaximum(u64, u64),
+    SupplyProofError(String),
 }
 
 impl Display for Error {
@@ -35,6 +50,17 @@ impl std::error::Error for Error {
             Self::Unknown => "unknown error",
             Self::MustBeNonConfidentialAsset => "must be use non-confidential asset",
             Self::AlreadySign => "already sign",
+            Self::SignatureAddressMismatch(_) => "public key of signature mismatch address",
+            Self::SignatureVerifyFailed(_) => "signature verify failed",
+            Self::DuplicateSignature(_) => "duplicate signature",
+            Self::TxidMismatch(_, _) => "txid mismatch transaction body",
+            Self::MultiSignatureThresholdError(_) => "multi-signature threshold out of range",
+            Self::MultiSignatureAddressMismatch(_) => "multi-signature address mismatch",
+            Self::MultiSignatureDuplicateKey(_) => "multi-signature has duplicate key",
+            Self::MultiSignatureNotEnough(_, _, _) => "multi-signature not enough",
+            Self::InvalidCommitment => "invalid commitment point",
+            Self::SupplyExceedMaximum(_, _) => "supply will exceed maximum",
+            Self::SupplyProofError(_) => "supply proof error",
         }
     }
 }
@@ -97,6 +123,56 @@ impl From<Error> for abcf::Error {
             Error::AlreadySign => {
                 abcf::Error::ABCIApplicationError(80009, String::from("already sign"))
             }
+            Error::SignatureAddressMismatch(a) => abcf::Error::ABCIApplicationError(
+                80010,
+                format!("public key of signature mismatch address: {:?}", a),
+            ),
+            Error::SignatureVerifyFailed(a) => abcf::Error::ABCIApplicationError(
+                80011,
+                format!("signature verify failed, address: {:?}", a),
+            ),
+            Error::DuplicateSignature(a) => abcf::Error::ABCIApplicationError(
+                80012,
+                format!("duplicate signature, address: {:?}", a),
+            ),
+            Error::TxidMismatch(provide, expect) => abcf::Error::ABCIApplicationError(
+                80013,
+                format!(
+                    "txid mismatch transaction body, provide: {:?}, expect: {:?}",
+                    provide, expect
+                ),
+            ),
+            Error::MultiSignatureThresholdError(a) => abcf::Error::ABCIApplicationError(
+                80014,
+                format!("multi-signature threshold out of range, address: {:?}", a),
+            ),
+            Error::MultiSignatureAddressMismatch(a) => abcf::Error::ABCIApplicationError(
+                80015,
+                format!("multi-signature address mismatch: {:?}", a),
+            ),
+            Error::MultiSignatureDuplicateKey(a) => abcf::Error::ABCIApplicationError(
+                80016,
+                format!("multi-signature has duplicate key, address: {:?}", a),
+            ),
+            Error::MultiSignatureNotEnough(a, signed, threshold) => {
+                abcf::Error::ABCIApplicationError(
+                    80017,
+                    format!(
+                        "multi-signature not enough, address: {:?}, signed: {}, threshold: {}",
+                        a, signed, threshold
+                    ),
+                )
+            }
+            Error::InvalidCommitment => {
+                abcf::Error::ABCIApplicationError(80018, String::from("invalid commitment point"))
+            }
+            Error::SupplyExceedMaximum(supply, maximum) => abcf::Error::ABCIApplicationError(
+                80019,
+                format!("supply {} will exceed maximum {}", supply, maximum),
+            ),
+            Error::SupplyProofError(e) => {
+                abcf::Error::ABCIApplicationError(80020, format!("supply proof error: {}", e))
+            }
             Error::Unknown => {
                 abcf::Error::ABCIApplicationError(81000, String::from("Only placeholder"))
             }
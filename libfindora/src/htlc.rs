@@ -0,0 +1,43 @@
+use digest::Digest;
+use primitive_types::H256;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use crate::Address;
+
+/// Hash-time-lock of output.
+///
+/// Owner of output can claim it with preimage before deadline,
+/// sender can refund it since deadline.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HashLock {
+    /// SHA-256 of preimage.
+    pub hash: H256,
+    /// Block height of deadline.
+    pub deadline: i64,
+    /// Refund to this address.
+    pub sender: Address,
+}
+
+impl HashLock {
+    pub fn hash_preimage(preimage: &[u8]) -> H256 {
+        H256::from_slice(&Sha256::digest(preimage))
+    }
+
+    pub fn is_preimage(&self, preimage: &[u8]) -> bool {
+        Self::hash_preimage(preimage) == self.hash
+    }
+
+    pub fn is_expired(&self, height: i64) -> bool {
+        height >= self.deadline
+    }
+}
+
+/// Spend condition of hash-time-locked output.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Unlock {
+    /// Claim by owner with preimage.
+    Claim(Vec<u8>),
+    /// Refund to sender.
+    Refund,
+}
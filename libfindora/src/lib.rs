@@ -1,7 +1,9 @@
 pub mod asset;
 pub mod evm;
+pub mod htlc;
 pub mod rewards;
 pub mod staking;
+pub mod supply;
 pub mod utxo;
 
 pub mod transaction;
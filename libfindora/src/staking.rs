@@ -1,11 +1,15 @@
 use abcf::tm_protos::crypto;
 use serde::{Deserialize, Serialize};
 
+use crate::{asset::Amount, Address};
+
 #[derive(Debug, Clone)]
 pub struct Delegate {
     pub address: TendermintAddress,
     pub validator: Option<ValidatorPublicKey>,
     pub memo: Option<Vec<u8>>,
+    /// Profile of validator, only for self-delegation.
+    pub profile: Option<ValidatorProfile>,
 }
 
 #[derive(Debug, Clone)]
@@ -13,6 +17,61 @@ pub struct Undelegate {
     pub address: TendermintAddress,
 }
 
+/// Move delegated amount from validator to another without unbonding.
+#[derive(Debug, Clone)]
+pub struct Redelegate {
+    pub from: TendermintAddress,
+    pub to: TendermintAddress,
+}
+
+/// Return jailed validator to active set after cooldown, must be signed by staker.
+#[derive(Debug, Clone)]
+pub struct Unjail {
+    pub address: TendermintAddress,
+}
+
+/// Cancel pending unbonding, amount is delegated back to validator.
+#[derive(Debug, Clone)]
+pub struct CancelUnbonding {
+    pub id: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnbondingStatus {
+    Pending,
+    Completed,
+    Cancelled,
+}
+
+/// Undelegated amount waiting to be paid out at completion height.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UnbondingEntry {
+    pub delegator: Address,
+    pub validator: TendermintAddress,
+    pub amount: Amount,
+    pub height: i64,
+    pub status: UnbondingStatus,
+}
+
+/// Update profile of validator, must be signed by staker.
+#[derive(Debug, Clone)]
+pub struct UpdateValidator {
+    pub address: TendermintAddress,
+    pub profile: ValidatorProfile,
+}
+
+/// Denominator of commission rate, rate is in millionths.
+pub const COMMISSION_RATE_DENOMINATOR: u64 = 1_000_000;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidatorProfile {
+    pub name: String,
+    pub website: String,
+    pub description: String,
+    /// Part of rewards kept by staker, in millionths.
+    pub commission_rate: u64,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct TendermintAddress(pub [u8; 20]);
 
@@ -0,0 +1,290 @@
+//! Issued supply of asset with confidential amount.
+//!
+//! Asset module keeps a Pedersen commitment to confidential part of issued
+//! supply. To issue under maximum, issuer proves both amount and
+//! `maximum - supply` are in `[0, 2^64)` by one aggregated range proof, so
+//! supply never exceeds maximum without revealing amount.
+
+use bulletproofs::{BulletproofGens, PedersenGens, RangeProof};
+use curve25519_dalek::{
+    ristretto::{CompressedRistretto, RistrettoPoint},
+    scalar::Scalar,
+    traits::Identity,
+};
+use merlin::Transcript;
+use primitive_types::H256;
+use rand_core::{CryptoRng, RngCore};
+use serde::{Deserialize, Serialize};
+use zei::xfr::structs::{OpenAssetRecord, XfrAmount};
+
+use crate::{Error, Result};
+
+const SUPPLY_PROOF_BITS: usize = 64;
+const SUPPLY_PROOF_PARTIES: usize = 2;
+const SUPPLY_TRANSCRIPT_LABEL: &[u8] = b"Findora Asset Supply";
+
+/// Proof of issue amount and remaining supply are both non-negative.
+#[derive(Debug, Clone)]
+pub struct SupplyProof {
+    pub range_proof: RangeProof,
+}
+
+/// Opening of commitment to issued supply, kept by issuer.
+///
+/// `amount` includes non-confidential issued amount, `blind` is bytes of sum
+/// of blinds of confidential amounts.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SupplyOpening {
+    pub amount: u64,
+    pub blind: [u8; 32],
+}
+
+impl SupplyOpening {
+    /// Opening of supply only issued non-confidential amount.
+    pub fn new(issued: u64) -> Self {
+        Self {
+            amount: issued,
+            blind: [0u8; 32],
+        }
+    }
+
+    pub fn blind_scalar(&self) -> Scalar {
+        Scalar::from_bytes_mod_order(self.blind)
+    }
+
+    /// Check this opens supply commitment of `issued` and `confidential` on chain.
+    pub fn is_opening(&self, issued: u64, confidential: Option<&H256>) -> Result<bool> {
+        let commitment =
+            PedersenGens::default().commit(Scalar::from(self.amount), self.blind_scalar());
+
+        Ok(supply_commitment(issued, confidential)? == commitment)
+    }
+}
+
+fn transcript() -> Transcript {
+    Transcript::new(SUPPLY_TRANSCRIPT_LABEL)
+}
+
+fn decompress(point: &CompressedRistretto) -> Result<RistrettoPoint> {
+    point.decompress().ok_or(Error::InvalidCommitment)
+}
+
+/// Commitment to amount, zei splits confidential amount into low and high 32 bits.
+pub fn amount_commitment(amount: &XfrAmount) -> Result<RistrettoPoint> {
+    match amount {
+        XfrAmount::NonConfidential(a) => Ok(PedersenGens::default().B * Scalar::from(*a)),
+        XfrAmount::Confidential((low, high)) => {
+            let low = decompress(&low.0)?;
+            let high = decompress(&high.0)?;
+            Ok(low + high * Scalar::from(1u64 << 32))
+        }
+    }
+}
+
+/// Blind of amount commitment of record, zero for non-confidential amount.
+pub fn amount_blind(record: &OpenAssetRecord) -> Scalar {
+    let (low, high) = &record.amount_blinds;
+    low.0 + high.0 * Scalar::from(1u64 << 32)
+}
+
+/// Commitment to issued supply, `confidential` is the part kept by asset module.
+pub fn supply_commitment(issued: u64, confidential: Option<&H256>) -> Result<RistrettoPoint> {
+    let public = PedersenGens::default().B * Scalar::from(issued);
+
+    match confidential {
+        Some(c) => Ok(public + decompress(&CompressedRistretto(c.to_fixed_bytes()))?),
+        None => Ok(public),
+    }
+}
+
+/// Prove issuing record keeps supply under maximum, return proof and new opening.
+pub fn prove_supply<R: CryptoRng + RngCore>(
+    prng: &mut R,
+    opening: &SupplyOpening,
+    record: &OpenAssetRecord,
+    maximum: u64,
+) -> Result<(SupplyProof, SupplyOpening)> {
+    let amount = record.amount;
+    let blind = amount_blind(record);
+
+    let supply = opening
+        .amount
+        .checked_add(amount)
+        .ok_or(Error::OverflowAdd)?;
+    let rest = maximum
+        .checked_sub(supply)
+        .ok_or(Error::SupplyExceedMaximum(supply, maximum))?;
+
+    let new_blind = opening.blind_scalar() + blind;
+    let new_opening = SupplyOpening {
+        amount: supply,
+        blind: new_blind.to_bytes(),
+    };
+
+    let (range_proof, _) = RangeProof::prove_multiple_with_rng(
+        &BulletproofGens::new(SUPPLY_PROOF_BITS, SUPPLY_PROOF_PARTIES),
+        &PedersenGens::default(),
+        &mut transcript(),
+        &[amount, rest],
+        &[blind, -new_blind],
+        SUPPLY_PROOF_BITS,
+        prng,
+    )
+    .map_err(|e| Error::SupplyProofError(format!("{:?}", e)))?;
+
+    Ok((SupplyProof { range_proof }, new_opening))
+}
+
+/// Verify issuing amount keeps supply under maximum.
+///
+/// Return new confidential part of supply commitment.
+pub fn verify_supply(
+    proof: &SupplyProof,
+    issued: u64,
+    confidential: Option<&H256>,
+    amount: &XfrAmount,
+    maximum: u64,
+) -> Result<H256> {
+    let supply = supply_commitment(issued, confidential)?;
+    let amount_point = amount_commitment(amount)?;
+    let rest = PedersenGens::default().B * Scalar::from(maximum) - supply - amount_point;
+
+    proof
+        .range_proof
+        .verify_multiple(
+            &BulletproofGens::new(SUPPLY_PROOF_BITS, SUPPLY_PROOF_PARTIES),
+            &PedersenGens::default(),
+            &mut transcript(),
+            &[amount_point.compress(), rest.compress()],
+            SUPPLY_PROOF_BITS,
+        )
+        .map_err(|e| Error::SupplyProofError(format!("{:?}", e)))?;
+
+    let old = match confidential {
+        Some(c) => decompress(&CompressedRistretto(c.to_fixed_bytes()))?,
+        None => RistrettoPoint::identity(),
+    };
+
+    let new = if amount.is_confidential() {
+        old + amount_point
+    } else {
+        old
+    };
+
+    Ok(H256::from(new.compress().to_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asset::FRA;
+    use rand_chacha::ChaChaRng;
+    use rand_core::SeedableRng;
+    use zei::xfr::{
+        asset_record::AssetRecordType,
+        sig::XfrKeyPair,
+        structs::{AssetRecord, AssetRecordTemplate},
+    };
+
+    const MAXIMUM: u64 = 1000;
+
+    fn record(prng: &mut ChaChaRng, amount: u64, confidential: bool) -> OpenAssetRecord {
+        let keypair = XfrKeyPair::generate(prng);
+        let template = AssetRecordTemplate::with_no_asset_tracing(
+            amount,
+            FRA.bare_asset_type,
+            AssetRecordType::from_flags(confidential, false),
+            keypair.get_pk(),
+        );
+
+        AssetRecord::from_template_no_identity_tracing(prng, &template)
+            .unwrap()
+            .open_asset_record
+    }
+
+    #[test]
+    fn test_non_confidential_round_trip() {
+        let mut prng = ChaChaRng::from_seed([0u8; 32]);
+        let record = record(&mut prng, 100, false);
+
+        let (proof, opening) =
+            prove_supply(&mut prng, &SupplyOpening::new(0), &record, MAXIMUM).unwrap();
+        let confidential =
+            verify_supply(&proof, 0, None, &record.blind_asset_record.amount, MAXIMUM).unwrap();
+
+        assert_eq!(opening, SupplyOpening::new(100));
+        assert_eq!(
+            confidential,
+            H256::from(RistrettoPoint::identity().compress().to_bytes())
+        );
+        assert!(opening.is_opening(100, None).unwrap());
+    }
+
+    #[test]
+    fn test_confidential_round_trip() {
+        let mut prng = ChaChaRng::from_seed([0u8; 32]);
+
+        let first = record(&mut prng, 100, true);
+        let (proof, opening) =
+            prove_supply(&mut prng, &SupplyOpening::new(0), &first, MAXIMUM).unwrap();
+        let commitment =
+            verify_supply(&proof, 0, None, &first.blind_asset_record.amount, MAXIMUM).unwrap();
+        assert!(opening.is_opening(0, Some(&commitment)).unwrap());
+
+        let second = record(&mut prng, 900, true);
+        let (proof, opening) = prove_supply(&mut prng, &opening, &second, MAXIMUM).unwrap();
+        let commitment = verify_supply(
+            &proof,
+            0,
+            Some(&commitment),
+            &second.blind_asset_record.amount,
+            MAXIMUM,
+        )
+        .unwrap();
+
+        assert_eq!(opening.amount, MAXIMUM);
+        assert!(opening.is_opening(0, Some(&commitment)).unwrap());
+    }
+
+    #[test]
+    fn test_issue_over_maximum() {
+        let mut prng = ChaChaRng::from_seed([0u8; 32]);
+        let record = record(&mut prng, 100, true);
+
+        let res = prove_supply(&mut prng, &SupplyOpening::new(950), &record, MAXIMUM);
+
+        assert!(matches!(
+            res,
+            Err(Error::SupplyExceedMaximum(1050, MAXIMUM))
+        ));
+    }
+
+    #[test]
+    fn test_tampered_commitment() {
+        let mut prng = ChaChaRng::from_seed([0u8; 32]);
+
+        let first = record(&mut prng, 100, true);
+        let (proof, opening) =
+            prove_supply(&mut prng, &SupplyOpening::new(0), &first, MAXIMUM).unwrap();
+        let commitment =
+            verify_supply(&proof, 0, None, &first.blind_asset_record.amount, MAXIMUM).unwrap();
+
+        // Supply looks smaller than it is, so more could be issued over maximum.
+        let point = decompress(&CompressedRistretto(commitment.to_fixed_bytes())).unwrap();
+        let tampered = point - PedersenGens::default().B * Scalar::from(100u64);
+        let tampered = H256::from(tampered.compress().to_bytes());
+        assert!(!opening.is_opening(0, Some(&tampered)).unwrap());
+
+        let second = record(&mut prng, 900, true);
+        let (proof, _) = prove_supply(&mut prng, &opening, &second, MAXIMUM).unwrap();
+        let res = verify_supply(
+            &proof,
+            0,
+            Some(&tampered),
+            &second.blind_asset_record.amount,
+            MAXIMUM,
+        );
+
+        assert!(res.is_err());
+    }
+}
@@ -1,7 +1,7 @@
 use primitive_types::H512;
 
 use crate::{
-    evm,
+    evm, htlc,
     transaction::{Input, InputOperation},
     transaction_capnp::input,
     Result,
@@ -24,6 +24,18 @@ pub fn from_input(input: input::Reader) -> Result<Input> {
 
             InputOperation::EvmCall(evm::Input { n })
         }
+        input::operation::Which::HashUnlock(a) => {
+            use crate::transaction_capnp::hash_unlock;
+
+            let reader = a?;
+
+            let unlock = match reader.which()? {
+                hash_unlock::Which::Claim(preimage) => htlc::Unlock::Claim(preimage?.to_vec()),
+                hash_unlock::Which::Refund(_) => htlc::Unlock::Refund,
+            };
+
+            InputOperation::HashUnlock(unlock)
+        }
     };
 
     Ok(Input { txid, n, operation })
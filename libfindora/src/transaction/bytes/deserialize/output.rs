@@ -1,11 +1,12 @@
-use primitive_types::U256;
+use primitive_types::{H256, U256};
 
 use crate::{
-    asset::AssetMeta,
-    rewards,
-    staking::{self, TendermintAddress, ValidatorPublicKey},
+    asset::{AssetConfig, AssetMeta},
+    htlc, rewards,
+    staking::{self, TendermintAddress, ValidatorProfile, ValidatorPublicKey},
+    supply::SupplyProof,
     transaction::{bytes::deserialize::evm::from_evm, Output, OutputOperation},
-    transaction_capnp::{address, output},
+    transaction_capnp::{address, output, validator_profile},
     utxo, Address, Result,
 };
 use zei::{
@@ -21,17 +22,28 @@ pub fn from_output(reader: output::Reader) -> Result<Output> {
     let asset = from_asset(reader.get_asset())?;
     let operation = from_operation(reader.get_operation())?;
     let owner_memo = from_owner_memo(reader.get_owner_memo())?;
+    let lock = from_lock(reader.get_lock())?;
 
     let core = utxo::Output {
         address,
         amount,
         asset,
         owner_memo,
+        lock,
     };
 
     Ok(Output { core, operation })
 }
 
+fn from_lock(reader: output::lock::Reader) -> Result<Option<utxo::Lock>> {
+    let lock = match reader.which()? {
+        output::lock::None(_) => None,
+        output::lock::Height(h) => Some(utxo::Lock::Height(h)),
+        output::lock::Timestamp(t) => Some(utxo::Lock::Timestamp(t)),
+    };
+    Ok(lock)
+}
+
 fn from_owner_memo(reader: output::owner_memo::Reader) -> Result<Option<OwnerMemo>> {
     let owner_memo = match reader.which()? {
         output::owner_memo::None(_) => None,
@@ -58,9 +70,19 @@ fn from_owner_memo(reader: output::owner_memo::Reader) -> Result<Option<OwnerMem
     Ok(owner_memo)
 }
 
+fn from_profile(reader: validator_profile::Reader) -> Result<ValidatorProfile> {
+    Ok(ValidatorProfile {
+        name: String::from(reader.get_name()?),
+        website: String::from(reader.get_website()?),
+        description: String::from(reader.get_description()?),
+        commission_rate: reader.get_commission_rate(),
+    })
+}
+
 fn from_operation(reader: output::operation::Reader) -> Result<OutputOperation> {
     use crate::transaction_capnp::define_asset;
     use crate::transaction_capnp::delegate_data::memo;
+    use crate::transaction_capnp::delegate_data::profile;
     use crate::transaction_capnp::delegate_data::validator;
     use crate::transaction_capnp::validator_key;
     use output::operation;
@@ -70,6 +92,7 @@ fn from_operation(reader: output::operation::Reader) -> Result<OutputOperation>
             let reader = e?;
 
             let transferable = reader.get_transferable();
+            let freezable = reader.get_freezable();
 
             let maximum = match reader.get_maximum().which()? {
                 define_asset::maximum::Which::None(_) => None,
@@ -79,9 +102,34 @@ fn from_operation(reader: output::operation::Reader) -> Result<OutputOperation>
                 }
             };
 
+            let name = match reader.get_name().which()? {
+                define_asset::name::Which::None(_) => None,
+                define_asset::name::Which::Some(a) => Some(String::from(a?)),
+            };
+
+            let symbol = match reader.get_symbol().which()? {
+                define_asset::symbol::Which::None(_) => None,
+                define_asset::symbol::Which::Some(a) => Some(String::from(a?)),
+            };
+
+            let decimals = reader.get_decimals();
+
+            let memo = match reader.get_memo().which()? {
+                define_asset::memo::Which::None(_) => None,
+                define_asset::memo::Which::Some(a) => Some(String::from(a?)),
+            };
+
+            let code = reader.get_code()?.to_vec();
+
             OutputOperation::DefineAsset(AssetMeta {
                 transferable,
                 maximum,
+                freezable,
+                name,
+                symbol,
+                decimals,
+                memo,
+                code,
             })
         }
         operation::Which::IssueAsset(_) => OutputOperation::IssueAsset,
@@ -115,12 +163,43 @@ fn from_operation(reader: output::operation::Reader) -> Result<OutputOperation>
                 memo::Which::Nono(_) => None,
             };
 
+            let profile = match reader.get_profile().which()? {
+                profile::Which::None(_) => None,
+                profile::Which::Some(p) => Some(from_profile(p?)?),
+            };
+
             OutputOperation::Delegate(staking::Delegate {
                 address: td_address,
                 validator,
                 memo,
+                profile,
+            })
+        }
+        operation::Which::UpdateValidator(a) => {
+            let reader = a?;
+            let address = reader.get_address()?;
+            let td_address = TendermintAddress(address.try_into()?);
+
+            OutputOperation::UpdateValidator(staking::UpdateValidator {
+                address: td_address,
+                profile: from_profile(reader.get_profile()?)?,
             })
         }
+        operation::Which::CancelUnbonding(id) => {
+            OutputOperation::CancelUnbonding(staking::CancelUnbonding { id })
+        }
+        operation::Which::Redelegate(a) => {
+            let reader = a?;
+            let from = TendermintAddress(reader.get_from()?.try_into()?);
+            let to = TendermintAddress(reader.get_to()?.try_into()?);
+
+            OutputOperation::Redelegate(staking::Redelegate { from, to })
+        }
+        operation::Which::Unjail(a) => {
+            let address = TendermintAddress(a?.try_into()?);
+
+            OutputOperation::Unjail(staking::Unjail { address })
+        }
         operation::Which::Undelegate(a) => {
             let reader = a?;
             let address = reader.get_address()?;
@@ -144,6 +223,34 @@ fn from_operation(reader: output::operation::Reader) -> Result<OutputOperation>
 
             OutputOperation::EvmCall(from_evm(reader)?)
         }
+        operation::Which::HashLock(a) => {
+            let reader = a?;
+
+            let hash = H256(reader.get_hash()?.try_into()?);
+            let deadline = reader.get_deadline();
+            let sender = from_address(reader.get_sender()?)?;
+
+            OutputOperation::HashLock(htlc::HashLock {
+                hash,
+                deadline,
+                sender,
+            })
+        }
+        operation::Which::TransferOwnership(_) => OutputOperation::TransferOwnership,
+        operation::Which::ConfigureAsset(a) => {
+            let reader = a?;
+
+            OutputOperation::ConfigureAsset(AssetConfig {
+                transferable: reader.get_transferable(),
+            })
+        }
+        operation::Which::FreezeAddress(a) => OutputOperation::FreezeAddress(from_address(a?)?),
+        operation::Which::UnfreezeAddress(a) => OutputOperation::UnfreezeAddress(from_address(a?)?),
+        operation::Which::Burn(_) => OutputOperation::Burn,
+        operation::Which::IssueAssetWithProof(a) => {
+            let range_proof = bulletproofs::RangeProof::zei_from_bytes(a?)?;
+            OutputOperation::IssueAssetWithProof(SupplyProof { range_proof })
+        }
     };
     Ok(operation)
 }
@@ -4,7 +4,7 @@ use zei::{
 };
 
 use crate::{
-    transaction::{FraSignature, Signature},
+    transaction::{FraSignature, MultiSignature, Signature},
     transaction_capnp::signature,
     Error, Result,
 };
@@ -31,6 +31,25 @@ pub fn from_signature(reader: signature::Reader) -> Result<Signature> {
                 signature,
             })
         }
+        signature::Multi(a) => {
+            let reader = a?;
+
+            let bytes = reader.get_address()?;
+            let address = from_address(bytes)?;
+
+            let threshold = reader.get_threshold();
+
+            let mut public_keys = Vec::new();
+            for bytes in reader.get_public_keys()?.iter() {
+                public_keys.push(XfrPublicKey::zei_from_bytes(bytes?)?);
+            }
+
+            Signature::Multi(MultiSignature {
+                address,
+                threshold,
+                public_keys,
+            })
+        }
         signature::None(_) => return Err(Error::Unknown),
     };
 
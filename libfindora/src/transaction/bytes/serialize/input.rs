@@ -1,4 +1,5 @@
 use crate::{
+    htlc::Unlock,
     transaction::{Input, InputOperation},
     transaction_capnp::input,
     Result,
@@ -19,6 +20,14 @@ pub fn build_input(input: &Input, builder: input::Builder) -> Result<()> {
 
             call.set_n(e.n);
         }
+        InputOperation::HashUnlock(u) => {
+            let mut unlock = operation.init_hash_unlock();
+
+            match u {
+                Unlock::Claim(preimage) => unlock.set_claim(preimage),
+                Unlock::Refund => unlock.set_refund(()),
+            }
+        }
     }
 
     Ok(())
@@ -6,13 +6,27 @@ mod proof;
 mod signature;
 
 use crate::{transaction::Transaction, transaction_capnp::transaction, Result};
+use primitive_types::H512;
 
 use self::memo::build_memos;
 
 pub fn build_transaction(tx: &Transaction, builder: transaction::Builder) -> Result<()> {
+    build(tx, builder, true)
+}
+
+/// Build the bytes covered by signatures: zero txid and no signatures.
+pub fn build_signature_payload(tx: &Transaction, builder: transaction::Builder) -> Result<()> {
+    build(tx, builder, false)
+}
+
+fn build(tx: &Transaction, builder: transaction::Builder, with_signature: bool) -> Result<()> {
     let mut builder = builder;
 
-    builder.set_txid(tx.txid.0.as_ref());
+    if with_signature {
+        builder.set_txid(tx.txid.0.as_ref());
+    } else {
+        builder.set_txid(H512::zero().0.as_ref());
+    }
 
     let inputs_len = tx.inputs.len().try_into()?;
     let mut inputs_builder = builder.reborrow().init_inputs(inputs_len);
@@ -33,12 +47,16 @@ pub fn build_transaction(tx: &Transaction, builder: transaction::Builder) -> Res
     let proof_builder = builder.reborrow().init_proof();
     proof::build_proof(&tx.proof, proof_builder)?;
 
-    let signature_len = tx.signatures.len().try_into()?;
-    let mut siganture_builder = builder.reborrow().init_signature(signature_len);
-    for index in 0..tx.signatures.len() {
-        let builder = siganture_builder.reborrow().get(index.try_into()?);
-        let signature = &tx.signatures[index];
-        signature::build_signature(signature, builder)?;
+    if with_signature {
+        let signature_len = tx.signatures.len().try_into()?;
+        let mut siganture_builder = builder.reborrow().init_signature(signature_len);
+        for index in 0..tx.signatures.len() {
+            let builder = siganture_builder.reborrow().get(index.try_into()?);
+            let signature = &tx.signatures[index];
+            signature::build_signature(signature, builder)?;
+        }
+    } else {
+        builder.reborrow().init_signature(0);
     }
 
     let memos = builder.init_memo();
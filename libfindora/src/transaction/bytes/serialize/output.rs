@@ -1,8 +1,8 @@
 use crate::{
-    staking::ValidatorPublicKey,
+    staking::{ValidatorProfile, ValidatorPublicKey},
     transaction::{Output, OutputOperation},
-    transaction_capnp::output,
-    Result,
+    transaction_capnp::{output, validator_profile},
+    utxo, Result,
 };
 use zei::{
     serialization::ZeiFromToBytes,
@@ -11,6 +11,15 @@ use zei::{
 
 use super::evm::build_evm;
 
+fn build_profile(profile: &ValidatorProfile, builder: validator_profile::Builder) {
+    let mut builder = builder;
+
+    builder.set_name(&profile.name);
+    builder.set_website(&profile.website);
+    builder.set_description(&profile.description);
+    builder.set_commission_rate(profile.commission_rate);
+}
+
 pub fn build_output(output: &Output, builder: output::Builder) -> Result<()> {
     let mut builder = builder;
 
@@ -53,12 +62,48 @@ pub fn build_output(output: &Output, builder: output::Builder) -> Result<()> {
         }
     }
 
+    {
+        let mut builder = builder.reborrow().init_lock();
+        match &output.core.lock {
+            Some(utxo::Lock::Height(h)) => builder.set_height(*h),
+            Some(utxo::Lock::Timestamp(t)) => builder.set_timestamp(*t),
+            None => builder.set_none(()),
+        }
+    }
+
     {
         let mut operation = builder.init_operation();
         match &output.operation {
             OutputOperation::DefineAsset(a) => {
                 let mut asset_meta = operation.init_define_asset();
                 asset_meta.set_transferable(a.transferable);
+                asset_meta.set_freezable(a.freezable);
+                asset_meta.set_decimals(a.decimals);
+                asset_meta.set_code(&a.code);
+
+                {
+                    let mut name = asset_meta.reborrow().init_name();
+                    match &a.name {
+                        Some(v) => name.set_some(v),
+                        None => name.set_none(()),
+                    }
+                }
+
+                {
+                    let mut symbol = asset_meta.reborrow().init_symbol();
+                    match &a.symbol {
+                        Some(v) => symbol.set_some(v),
+                        None => symbol.set_none(()),
+                    }
+                }
+
+                {
+                    let mut memo = asset_meta.reborrow().init_memo();
+                    match &a.memo {
+                        Some(v) => memo.set_some(v),
+                        None => memo.set_none(()),
+                    }
+                }
 
                 let mut maximum = asset_meta.init_maximum();
 
@@ -101,13 +146,32 @@ pub fn build_output(output: &Output, builder: output::Builder) -> Result<()> {
                     None => validator.set_none(()),
                 }
 
-                let mut memo = delegation.init_memo();
+                let mut memo = delegation.reborrow().init_memo();
 
                 match &a.memo {
                     Some(v) => memo.set_some(v.as_ref()),
                     None => memo.set_nono(()),
                 }
+
+                let mut profile = delegation.init_profile();
+
+                match &a.profile {
+                    Some(v) => build_profile(v, profile.init_some()),
+                    None => profile.set_none(()),
+                }
+            }
+            OutputOperation::UpdateValidator(a) => {
+                let mut update = operation.init_update_validator();
+                update.set_address(a.address.0.as_ref());
+                build_profile(&a.profile, update.init_profile());
             }
+            OutputOperation::CancelUnbonding(a) => operation.set_cancel_unbonding(a.id),
+            OutputOperation::Redelegate(a) => {
+                let mut redelegate = operation.init_redelegate();
+                redelegate.set_from(a.from.0.as_ref());
+                redelegate.set_to(a.to.0.as_ref());
+            }
+            OutputOperation::Unjail(a) => operation.set_unjail(a.address.0.as_ref()),
             OutputOperation::ClaimReward(a) => {
                 let mut claim = operation.init_claim_reward();
                 claim.set_validator(a.validator.0.as_ref());
@@ -116,6 +180,31 @@ pub fn build_output(output: &Output, builder: output::Builder) -> Result<()> {
                 let builder = operation.init_evm_call();
                 build_evm(a, builder)?;
             }
+            OutputOperation::HashLock(a) => {
+                let mut hash_lock = operation.init_hash_lock();
+                hash_lock.set_hash(a.hash.as_bytes());
+                hash_lock.set_deadline(a.deadline);
+
+                let mut sender = hash_lock.init_sender();
+                sender.set_address(a.sender.as_ref());
+            }
+            OutputOperation::TransferOwnership => operation.set_transfer_ownership(()),
+            OutputOperation::ConfigureAsset(a) => {
+                let mut config = operation.init_configure_asset();
+                config.set_transferable(a.transferable);
+            }
+            OutputOperation::FreezeAddress(a) => {
+                let mut address = operation.init_freeze_address();
+                address.set_address(a.as_ref());
+            }
+            OutputOperation::UnfreezeAddress(a) => {
+                let mut address = operation.init_unfreeze_address();
+                address.set_address(a.as_ref());
+            }
+            OutputOperation::Burn => operation.set_burn(()),
+            OutputOperation::IssueAssetWithProof(p) => {
+                operation.set_issue_asset_with_proof(&p.range_proof.zei_to_bytes())
+            }
         }
     }
 
@@ -11,6 +11,21 @@ pub fn build_signature(signature: &Signature, builder: signature::Builder) -> Re
 
             let mut builder = b.init_address();
 
+            builder.set_address(s.address.as_ref());
+        }
+        Signature::Multi(s) => {
+            let mut b = builder.init_multi();
+
+            b.set_threshold(s.threshold);
+
+            let keys_len = s.public_keys.len().try_into()?;
+            let mut keys = b.reborrow().init_public_keys(keys_len);
+            for (index, key) in s.public_keys.iter().enumerate() {
+                keys.set(index.try_into()?, key.zei_to_bytes().as_ref());
+            }
+
+            let mut builder = b.init_address();
+
             builder.set_address(s.address.as_ref());
         }
     }
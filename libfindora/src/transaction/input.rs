@@ -1,11 +1,12 @@
 use primitive_types::H512;
 
-use crate::evm;
+use crate::{evm, htlc};
 
 #[derive(Debug, Clone)]
 pub enum Operation {
     TransferAsset,
     EvmCall(evm::Input),
+    HashUnlock(htlc::Unlock),
 }
 
 #[derive(Debug, Clone)]
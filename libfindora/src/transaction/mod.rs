@@ -10,7 +10,7 @@ pub use output::{Operation as OutputOperation, Output};
 mod bytes;
 
 mod signature;
-pub use signature::{FraSignature, Signature};
+pub use signature::{FraSignature, MultiSignature, Signature};
 
 mod memo;
 pub use memo::Memo;
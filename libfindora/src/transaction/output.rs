@@ -1,8 +1,11 @@
-use crate::asset::AssetMeta;
+use crate::asset::{AssetConfig, AssetMeta};
 use crate::evm;
+use crate::htlc;
 use crate::rewards;
 use crate::staking;
+use crate::supply;
 use crate::utxo;
+use crate::Address;
 
 #[derive(Debug, Clone)]
 pub enum Operation {
@@ -13,7 +16,26 @@ pub enum Operation {
     Delegate(staking::Delegate),
     ClaimReward(rewards::Claim),
     Undelegate(staking::Undelegate),
+    /// Update name, website, description and commission rate of validator.
+    UpdateValidator(staking::UpdateValidator),
+    /// Cancel pending unbonding of delegator.
+    CancelUnbonding(staking::CancelUnbonding),
+    /// Move delegation of output address between validators.
+    Redelegate(staking::Redelegate),
+    /// Unjail validator after cooldown.
+    Unjail(staking::Unjail),
     EvmCall(evm::Evm),
+    HashLock(htlc::HashLock),
+    /// Transfer ownership of asset to address of output.
+    TransferOwnership,
+    ConfigureAsset(AssetConfig),
+    /// Freeze address from spending asset, signed by owner.
+    FreezeAddress(Address),
+    UnfreezeAddress(Address),
+    /// Destroy asset of output, counted as burned supply.
+    Burn,
+    /// Issue asset with proof that supply stays under maximum, amount can be confidential.
+    IssueAssetWithProof(supply::SupplyProof),
 }
 
 #[derive(Debug, Clone)]
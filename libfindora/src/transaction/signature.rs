@@ -1,6 +1,8 @@
+use std::collections::BTreeSet;
+
 use zei::xfr::sig::{XfrPublicKey, XfrSignature};
 
-use crate::Address;
+use crate::{Address, Error, Result};
 
 #[derive(Debug)]
 pub struct FraSignature {
@@ -9,7 +11,66 @@ pub struct FraSignature {
     pub signature: XfrSignature,
 }
 
+/// Declare a M-of-N owner, each key signs by `FraSignature`.
+#[derive(Debug, Clone)]
+pub struct MultiSignature {
+    pub address: Address,
+    pub threshold: u32,
+    pub public_keys: Vec<XfrPublicKey>,
+}
+
+impl MultiSignature {
+    pub fn new(threshold: u32, public_keys: Vec<XfrPublicKey>) -> Self {
+        let address = Address::from_multisig(threshold, &public_keys);
+
+        Self {
+            address,
+            threshold,
+            public_keys,
+        }
+    }
+
+    /// Check at least `threshold` keys of this owner in `signers`.
+    pub fn verify(&self, signers: &BTreeSet<Address>) -> Result<()> {
+        let threshold: usize = self.threshold.try_into()?;
+
+        if threshold == 0 || threshold > self.public_keys.len() {
+            return Err(Error::MultiSignatureThresholdError(self.address.clone()));
+        }
+
+        if Address::from_multisig(self.threshold, &self.public_keys) != self.address {
+            return Err(Error::MultiSignatureAddressMismatch(self.address.clone()));
+        }
+
+        let mut keys = BTreeSet::new();
+        let mut signed = 0;
+
+        for key in &self.public_keys {
+            let address = Address::from(*key);
+
+            if signers.contains(&address) {
+                signed += 1;
+            }
+
+            if !keys.insert(address) {
+                return Err(Error::MultiSignatureDuplicateKey(self.address.clone()));
+            }
+        }
+
+        if signed < threshold {
+            return Err(Error::MultiSignatureNotEnough(
+                self.address.clone(),
+                signed,
+                threshold,
+            ));
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Debug)]
 pub enum Signature {
     Fra(FraSignature),
+    Multi(MultiSignature),
 }
@@ -1,3 +1,5 @@
+use std::collections::BTreeSet;
+
 use abcf::ToBytes;
 use capnp::{message::ReaderOptions, serialize_packed};
 use digest::Digest;
@@ -43,7 +45,16 @@ impl abcf::module::FromBytes for Transaction {
     where
         Self: Sized,
     {
-        Ok(Transaction::deserialize(bytes)?)
+        let tx = Transaction::deserialize(bytes)?;
+
+        // Don't trust txid in message, it must be computed from transaction body.
+        let txid = tx.compute_txid()?;
+
+        if txid != tx.txid {
+            return Err(Error::TxidMismatch(tx.txid, txid).into());
+        }
+
+        Ok(tx)
     }
 }
 
@@ -67,12 +78,37 @@ impl Transaction {
         Ok(result)
     }
 
+    /// Canonical signing payload, the transaction serialized with zero txid and without signatures.
+    ///
+    /// Txid is also derived from these bytes, so it doesn't depend on signatures.
+    pub fn signature_bytes(&self) -> Result<Vec<u8>> {
+        let mut result = Vec::new();
+
+        let mut message = capnp::message::Builder::new_default();
+        let transaction = message.init_root::<transaction_capnp::transaction::Builder>();
+
+        serialize::build_signature_payload(self, transaction)?;
+        serialize_packed::write_message(&mut result, &message)?;
+
+        Ok(result)
+    }
+
+    pub fn compute_txid(&self) -> Result<H512> {
+        let bytes = self.signature_bytes()?;
+
+        let txid = Sha3_512::digest(&bytes);
+
+        Ok(H512::from_slice(txid.as_slice()))
+    }
+
     pub fn signature(&mut self, keypairs: &[XfrKeyPair]) -> Result<()> {
         if !self.signatures.is_empty() {
             return Err(Error::AlreadySign);
         }
 
-        let bytes = self.serialize()?;
+        let bytes = self.signature_bytes()?;
+
+        self.txid = self.compute_txid()?;
 
         for keypair in keypairs {
             let address = Address::from(keypair.get_pk());
@@ -88,21 +124,47 @@ impl Transaction {
             }))
         }
 
-        let bytes = self.serialize()?;
-
-        let txid = Sha3_512::digest(&bytes);
-
-        self.txid = H512::from_slice(txid.as_slice());
-
         Ok(())
     }
 
-    pub fn verify(&self, _bytes: &[u8]) -> Result<bool> {
-        // verify tx signature using  here.
+    /// Verify all signatures of this transaction, return addresses which signed it.
+    ///
+    /// Caller must check owner of each spent output is in the returned set.
+    pub fn verify(&self) -> Result<BTreeSet<Address>> {
+        let bytes = self.signature_bytes()?;
+
+        let mut signers = BTreeSet::new();
+        let mut multisigs = Vec::new();
+
+        for signature in &self.signatures {
+            match signature {
+                Signature::Fra(s) => {
+                    if Address::from(s.public_key) != s.address {
+                        return Err(Error::SignatureAddressMismatch(s.address.clone()));
+                    }
+
+                    s.public_key
+                        .verify(&bytes, &s.signature)
+                        .map_err(|_| Error::SignatureVerifyFailed(s.address.clone()))?;
+
+                    if !signers.insert(s.address.clone()) {
+                        return Err(Error::DuplicateSignature(s.address.clone()));
+                    }
+                }
+                Signature::Multi(s) => multisigs.push(s),
+            }
+        }
+
+        // Multi-signature owner signed when enough keys signed.
+        for s in multisigs {
+            s.verify(&signers)?;
 
-        // skip memo for ethereum.
+            if !signers.insert(s.address.clone()) {
+                return Err(Error::DuplicateSignature(s.address.clone()));
+            }
+        }
 
-        Ok(true)
+        Ok(signers)
     }
 }
 
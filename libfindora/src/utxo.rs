@@ -10,12 +10,31 @@ pub struct OutputId {
     pub n: u32,
 }
 
+/// Condition before which output can't be spent.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub enum Lock {
+    /// Locked until this block height.
+    Height(i64),
+    /// Locked until this unix timestamp, in seconds.
+    Timestamp(i64),
+}
+
+impl Lock {
+    pub fn is_unlocked(&self, height: i64, timestamp: i64) -> bool {
+        match self {
+            Lock::Height(h) => height >= *h,
+            Lock::Timestamp(t) => timestamp >= *t,
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct Output {
     pub amount: XfrAmount,
     pub asset: XfrAssetType,
     pub address: Address,
     pub owner_memo: Option<OwnerMemo>,
+    pub lock: Option<Lock>,
 }
 
 impl Output {
@@ -36,6 +55,7 @@ impl Output {
             asset: ar.asset_type,
             address,
             owner_memo,
+            lock: None,
         }
     }
 }
@@ -1,15 +1,18 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
-use crate::{entity::Entity, mapper::Mapper, net, utils, Error, Result};
+use crate::{entity::Entity, mapper::Mapper, net, utils, utils::CoinSelection, Error, Result};
 use abcf_sdk::providers::Provider;
 use libfindora::{
-    asset::FRA,
-    transaction::{Input, InputOperation, Output, OutputOperation},
-    utxo, Address, Transaction,
+    asset::{Amount, AssetType, FRA},
+    htlc,
+    supply::{self, SupplyOpening},
+    transaction::{Input, InputOperation, MultiSignature, Output, OutputOperation, Signature},
+    utxo::{self, OutputId},
+    Address, Transaction,
 };
 use primitive_types::H512;
 use rand_core::{CryptoRng, RngCore};
-use zei::xfr::structs::{AssetTypeAndAmountProof, XfrBody, XfrProofs};
+use zei::xfr::structs::{AssetTypeAndAmountProof, XfrAmount, XfrBody, XfrProofs};
 use zei::xfr::{lib::gen_xfr_body, sig::XfrKeyPair, structs::AssetRecord};
 
 /// Transaction builder
@@ -25,11 +28,35 @@ pub struct Builder {
     pub zei_outputs: Vec<AssetRecord>,
     /// MAP of the transaction originator
     pub keypairs: BTreeMap<Address, XfrKeyPair>,
+    /// MAP of the multi-signature originator
+    pub multisigs: BTreeMap<Address, MultiSignature>,
+    /// Keypairs sign for multi-signature originator
+    pub cosigners: BTreeMap<Address, XfrKeyPair>,
     /// Objects of calculation for utxo
     pub mapper: Mapper,
+    /// Chain height, outputs locked after it are not spent
+    pub height: i64,
+    /// Chain time, outputs locked after it are not spent
+    pub timestamp: i64,
+    /// Owned outputs fetched but not spent yet
+    pub candidates: BTreeMap<Address, Vec<(OutputId, AssetRecord)>>,
+    /// Strategy to pick inputs from candidates
+    pub coin_selection: CoinSelection,
+    /// Supply openings after issue, issuer should keep them for next issue
+    pub supply_openings: BTreeMap<AssetType, SupplyOpening>,
 }
 
 impl Builder {
+    /// Fetch chain status, so outputs unlocked can be spent.
+    pub async fn fetch_status<P: Provider>(&mut self, provider: &mut P) -> Result<()> {
+        let (height, timestamp) = net::status::get(provider).await?;
+
+        self.height = height;
+        self.timestamp = timestamp;
+
+        Ok(())
+    }
+
     pub async fn fetch_owned_utxo<P: Provider>(
         &mut self,
         provider: &mut P,
@@ -38,8 +65,147 @@ impl Builder {
     ) -> Result<()> {
         if !self.keypairs.contains_key(address) {
             let (ids, outputs) = net::owned_outputs::get(provider, address).await?;
+            let (ids, outputs) = utils::unlocked_outputs(ids, outputs, self.height, self.timestamp);
+
+            let ars = utils::open_outputs(outputs, keypair)?;
+
+            // Here the utxo of the person who initiated the transaction is fetched,
+            // they are put into the input of the transaction when needed.
+            self.candidates
+                .insert(address.clone(), ids.into_iter().zip(ars).collect());
+
+            self.keypairs.insert(address.clone(), keypair.clone());
+        }
+        Ok(())
+    }
+
+    pub async fn fetch_multisig_utxo<P: Provider>(
+        &mut self,
+        provider: &mut P,
+        multisig: MultiSignature,
+        keypair: &XfrKeyPair,
+    ) -> Result<()> {
+        let address = multisig.address.clone();
+
+        if !self.multisigs.contains_key(&address) {
+            let (ids, outputs) = net::owned_outputs::get(provider, &address).await?;
+            let (ids, outputs) = utils::unlocked_outputs(ids, outputs, self.height, self.timestamp);
+
+            // Outputs of multi-signature owner are non-confidential, any keypair can open it.
+            let ars = utils::open_outputs(outputs, keypair)?;
+
+            self.candidates
+                .insert(address.clone(), ids.into_iter().zip(ars).collect());
+
+            self.multisigs.insert(address, multisig);
+        }
+        Ok(())
+    }
+
+    /// Pick inputs from candidates of address, until balance of asset covers amount.
+    pub fn select_utxo<R: RngCore>(
+        &mut self,
+        prng: &mut R,
+        address: &Address,
+        asset: &AssetType,
+        amount: Amount,
+    ) -> Result<()> {
+        let balance = self.mapper.balance(address, asset);
+
+        if balance >= amount {
+            return Ok(());
+        }
+
+        let candidates = match self.candidates.get_mut(address) {
+            Some(c) => c,
+            None => return Ok(()),
+        };
+
+        let (mut pool, rest): (Vec<_>, Vec<_>) = candidates
+            .drain(..)
+            .partition(|(_, ar)| &ar.open_asset_record.asset_type == asset);
+
+        let selected = utils::select_coins(self.coin_selection, prng, &mut pool, amount - balance);
+
+        candidates.extend(rest);
+        candidates.append(&mut pool);
+
+        for (id, ar) in selected {
+            self.mapper.add(
+                address,
+                &ar.open_asset_record.asset_type,
+                ar.open_asset_record.amount,
+                false,
+                false,
+            )?;
+
+            self.zei_inputs.push(ar);
+
+            self.inputs.push(Input {
+                txid: id.txid,
+                n: id.n,
+                operation: InputOperation::TransferAsset,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Spend a hash-time-locked output to the keypair.
+    pub async fn fetch_hash_locked_utxo<P: Provider>(
+        &mut self,
+        provider: &mut P,
+        output_id: &OutputId,
+        keypair: &XfrKeyPair,
+        unlock: htlc::Unlock,
+    ) -> Result<()> {
+        let address = Address::from(keypair.get_pk());
+
+        self.fetch_owned_utxo(provider, &address, keypair).await?;
+
+        let output = net::owned_outputs::get_output(provider, output_id)
+            .await?
+            .ok_or(Error::OutputNotFound)?;
+
+        let mut ars = utils::open_outputs(vec![output], keypair)?;
+
+        for ar in &ars {
+            self.mapper.add(
+                &address,
+                &ar.open_asset_record.asset_type,
+                ar.open_asset_record.amount,
+                false,
+                false,
+            )?;
+        }
+
+        self.zei_inputs.append(&mut ars);
+
+        self.inputs.push(Input {
+            txid: output_id.txid,
+            n: output_id.n,
+            operation: InputOperation::HashUnlock(unlock),
+        });
+
+        Ok(())
+    }
+
+    /// Spend a bounded set of small outputs of asset, they are merged into change.
+    pub async fn fetch_consolidate_utxo<P: Provider>(
+        &mut self,
+        provider: &mut P,
+        address: &Address,
+        keypair: &XfrKeyPair,
+        asset: &AssetType,
+        max_inputs: usize,
+    ) -> Result<()> {
+        if !self.keypairs.contains_key(address) {
+            let (ids, outputs) = net::owned_outputs::get(provider, address).await?;
+            let (ids, outputs) = utils::unlocked_outputs(ids, outputs, self.height, self.timestamp);
+
+            let ars = utils::open_outputs(outputs, keypair)?;
 
-            let mut ars = utils::open_outputs(outputs, keypair)?;
+            let (ids, mut ars) = utils::select_consolidation(ids, ars, asset, max_inputs)?;
 
             for ar in &ars {
                 self.mapper.add(
@@ -51,7 +217,6 @@ impl Builder {
                 )?;
             }
 
-            // Here the utxo of the person who initiated the transaction is fetched and put into the input of the transaction
             self.zei_inputs.append(&mut ars);
 
             for index in ids {
@@ -84,6 +249,30 @@ impl Builder {
                     let output = e.to_output();
                     self.outputs.push(output);
                 }
+                Entity::TransferOwnership(e) => {
+                    let keypair = e.to_keypair();
+                    let address = Address::from(keypair.get_pk());
+
+                    self.fetch_owned_utxo(provider, &address, &keypair).await?;
+
+                    self.outputs.push(e.to_output());
+                }
+                Entity::ConfigureAsset(e) => {
+                    let keypair = e.to_keypair();
+                    let address = Address::from(keypair.get_pk());
+
+                    self.fetch_owned_utxo(provider, &address, &keypair).await?;
+
+                    self.outputs.push(e.to_output());
+                }
+                Entity::FreezeAddress(e) => {
+                    let keypair = e.to_keypair();
+                    let address = Address::from(keypair.get_pk());
+
+                    self.fetch_owned_utxo(provider, &address, &keypair).await?;
+
+                    self.outputs.push(e.to_output());
+                }
                 Entity::Issue(e) => {
                     let record = e.to_output_asset_record(prng)?;
 
@@ -109,12 +298,24 @@ impl Builder {
                             .clone(),
                         address: address.clone(),
                         owner_memo: record.owner_memo.clone(),
+                        lock: None,
                     };
 
-                    self.outputs.push(Output {
-                        operation: OutputOperation::IssueAsset,
-                        core,
-                    });
+                    let operation = match &e.prove_supply {
+                        Some(p) => {
+                            let (proof, opening) = supply::prove_supply(
+                                prng,
+                                &p.opening,
+                                &record.open_asset_record,
+                                p.maximum,
+                            )?;
+                            self.supply_openings.insert(e.asset_type, opening);
+                            OutputOperation::IssueAssetWithProof(proof)
+                        }
+                        None => OutputOperation::IssueAsset,
+                    };
+
+                    self.outputs.push(Output { operation, core });
 
                     self.zei_inputs.push(record);
 
@@ -137,6 +338,13 @@ impl Builder {
 
                     self.fetch_owned_utxo(provider, &address, &keypair).await?;
 
+                    self.select_utxo(
+                        prng,
+                        &address,
+                        &record.open_asset_record.asset_type,
+                        record.open_asset_record.amount,
+                    )?;
+
                     self.mapper.sub(
                         &address,
                         &record.open_asset_record.asset_type,
@@ -154,6 +362,7 @@ impl Builder {
                             .clone(),
                         address: t.address.clone(),
                         owner_memo: record.owner_memo.clone(),
+                        lock: t.lock.clone(),
                     };
 
                     self.outputs.push(Output {
@@ -163,6 +372,164 @@ impl Builder {
 
                     self.zei_outputs.push(record);
                 }
+                Entity::MultiSigTransfer(t) => {
+                    let address = t.to_input_address();
+                    let keypairs = t.to_keypairs()?;
+                    let keypair = keypairs.first().ok_or(Error::KeyMustBeSet)?;
+
+                    let record = t.to_output_asset_record(prng)?;
+
+                    self.fetch_multisig_utxo(provider, t.to_multi_signature(), keypair)
+                        .await?;
+
+                    self.select_utxo(
+                        prng,
+                        &address,
+                        &record.open_asset_record.asset_type,
+                        record.open_asset_record.amount,
+                    )?;
+
+                    self.mapper.sub(
+                        &address,
+                        &record.open_asset_record.asset_type,
+                        record.open_asset_record.amount,
+                        false,
+                        false,
+                    )?;
+
+                    let core = utxo::Output {
+                        amount: record.open_asset_record.blind_asset_record.amount.clone(),
+                        asset: record
+                            .open_asset_record
+                            .blind_asset_record
+                            .asset_type
+                            .clone(),
+                        address: t.address.clone(),
+                        owner_memo: record.owner_memo.clone(),
+                        lock: None,
+                    };
+
+                    self.outputs.push(Output {
+                        operation: OutputOperation::TransferAsset,
+                        core,
+                    });
+
+                    self.zei_outputs.push(record);
+
+                    for keypair in keypairs {
+                        self.cosigners
+                            .insert(Address::from(keypair.get_pk()), keypair);
+                    }
+                }
+                Entity::HashLock(e) => {
+                    let address = e.to_input_address();
+                    let keypair = e.to_keypair();
+
+                    let record = e.to_output_asset_record(prng)?;
+
+                    self.fetch_owned_utxo(provider, &address, &keypair).await?;
+
+                    self.select_utxo(
+                        prng,
+                        &address,
+                        &record.open_asset_record.asset_type,
+                        record.open_asset_record.amount,
+                    )?;
+
+                    self.mapper.sub(
+                        &address,
+                        &record.open_asset_record.asset_type,
+                        record.open_asset_record.amount,
+                        false,
+                        false,
+                    )?;
+
+                    let core = utxo::Output {
+                        amount: record.open_asset_record.blind_asset_record.amount.clone(),
+                        asset: record
+                            .open_asset_record
+                            .blind_asset_record
+                            .asset_type
+                            .clone(),
+                        address: e.address.clone(),
+                        owner_memo: None,
+                        lock: None,
+                    };
+
+                    self.outputs.push(Output {
+                        operation: OutputOperation::HashLock(e.to_operation()),
+                        core,
+                    });
+
+                    self.zei_outputs.push(record);
+                }
+                Entity::Burn(e) => {
+                    let address = e.to_input_address();
+                    let keypair = e.to_keypair();
+
+                    let record = e.to_output_asset_record(prng)?;
+
+                    self.fetch_owned_utxo(provider, &address, &keypair).await?;
+
+                    self.select_utxo(
+                        prng,
+                        &address,
+                        &record.open_asset_record.asset_type,
+                        record.open_asset_record.amount,
+                    )?;
+
+                    self.mapper.sub(
+                        &address,
+                        &record.open_asset_record.asset_type,
+                        record.open_asset_record.amount,
+                        false,
+                        false,
+                    )?;
+
+                    let core = utxo::Output {
+                        amount: record.open_asset_record.blind_asset_record.amount.clone(),
+                        asset: record
+                            .open_asset_record
+                            .blind_asset_record
+                            .asset_type
+                            .clone(),
+                        address: address.clone(),
+                        owner_memo: None,
+                        lock: None,
+                    };
+
+                    self.outputs.push(Output {
+                        operation: OutputOperation::Burn,
+                        core,
+                    });
+
+                    self.zei_outputs.push(record);
+                }
+                Entity::HashClaim(e) => {
+                    let keypair = e.to_keypair();
+
+                    self.fetch_hash_locked_utxo(provider, &e.output_id, &keypair, e.to_unlock())
+                        .await?;
+                }
+                Entity::HashRefund(e) => {
+                    let keypair = e.to_keypair();
+
+                    self.fetch_hash_locked_utxo(provider, &e.output_id, &keypair, e.to_unlock())
+                        .await?;
+                }
+                Entity::Consolidate(e) => {
+                    let address = e.to_input_address();
+                    let keypair = e.to_keypair();
+
+                    self.fetch_consolidate_utxo(
+                        provider,
+                        &address,
+                        &keypair,
+                        &e.asset_type,
+                        e.max_inputs,
+                    )
+                    .await?;
+                }
                 Entity::Delegate(d) => {
                     let output = d.to_output(prng)?;
 
@@ -174,6 +541,13 @@ impl Builder {
 
                     self.fetch_owned_utxo(provider, &address, &keypair).await?;
 
+                    self.select_utxo(
+                        prng,
+                        &address,
+                        &output.open_asset_record.asset_type,
+                        output.open_asset_record.amount,
+                    )?;
+
                     self.mapper.sub(
                         &address,
                         &output.open_asset_record.asset_type,
@@ -186,6 +560,7 @@ impl Builder {
                         amount: bar.amount.clone(),
                         asset: bar.asset_type.clone(),
                         owner_memo: None,
+                        lock: None,
                         address: address.clone(),
                     };
 
@@ -211,6 +586,13 @@ impl Builder {
 
                     self.fetch_owned_utxo(provider, &address, &keypair).await?;
 
+                    self.select_utxo(
+                        prng,
+                        &address,
+                        &output.open_asset_record.asset_type,
+                        output.open_asset_record.amount,
+                    )?;
+
                     self.mapper.sub(
                         &address,
                         &output.open_asset_record.asset_type,
@@ -223,6 +605,7 @@ impl Builder {
                         amount: bar.amount.clone(),
                         asset: bar.asset_type.clone(),
                         owner_memo: None,
+                        lock: None,
                         address: address.clone(),
                     };
 
@@ -258,6 +641,7 @@ impl Builder {
                             .clone(),
                         address: address.clone(),
                         owner_memo: record.owner_memo.clone(),
+                        lock: None,
                     };
 
                     self.outputs.push(Output {
@@ -283,6 +667,117 @@ impl Builder {
 
                     self.keypairs.insert(address, keypair);
                 }
+
+                Entity::UpdateValidator(e) => {
+                    let address = Address::from(e.keypair.get_pk());
+                    let keypair = e.to_keypair();
+
+                    self.fetch_owned_utxo(provider, &address, &keypair).await?;
+
+                    let core = utxo::Output {
+                        amount: XfrAmount::NonConfidential(0),
+                        asset: FRA.asset_type,
+                        address: address.clone(),
+                        owner_memo: None,
+                        lock: None,
+                    };
+
+                    self.outputs.push(Output {
+                        core,
+                        operation: OutputOperation::UpdateValidator(e.to_operation()),
+                    });
+
+                    self.keypairs.insert(address, keypair);
+                }
+
+                Entity::Redelegate(e) => {
+                    let address = Address::from(e.keypair.get_pk());
+                    let keypair = e.to_keypair();
+
+                    self.fetch_owned_utxo(provider, &address, &keypair).await?;
+
+                    let core = utxo::Output {
+                        amount: XfrAmount::NonConfidential(e.amount),
+                        asset: FRA.asset_type,
+                        address: address.clone(),
+                        owner_memo: None,
+                        lock: None,
+                    };
+
+                    self.outputs.push(Output {
+                        core,
+                        operation: OutputOperation::Redelegate(e.to_operation()),
+                    });
+
+                    self.keypairs.insert(address, keypair);
+                }
+
+                Entity::Unjail(e) => {
+                    let address = Address::from(e.keypair.get_pk());
+                    let keypair = e.to_keypair();
+
+                    self.fetch_owned_utxo(provider, &address, &keypair).await?;
+
+                    let core = utxo::Output {
+                        amount: XfrAmount::NonConfidential(0),
+                        asset: FRA.asset_type,
+                        address: address.clone(),
+                        owner_memo: None,
+                        lock: None,
+                    };
+
+                    self.outputs.push(Output {
+                        core,
+                        operation: OutputOperation::Unjail(e.to_operation()),
+                    });
+
+                    self.keypairs.insert(address, keypair);
+                }
+
+                Entity::CancelUnbonding(e) => {
+                    let address = Address::from(e.keypair.get_pk());
+                    let keypair = e.to_keypair();
+
+                    self.fetch_owned_utxo(provider, &address, &keypair).await?;
+
+                    let core = utxo::Output {
+                        amount: XfrAmount::NonConfidential(0),
+                        asset: FRA.asset_type,
+                        address: address.clone(),
+                        owner_memo: None,
+                        lock: None,
+                    };
+
+                    self.outputs.push(Output {
+                        core,
+                        operation: OutputOperation::CancelUnbonding(e.to_operation()),
+                    });
+
+                    self.keypairs.insert(address, keypair);
+                }
+
+                Entity::ClaimReward(e) => {
+                    let address = Address::from(e.keypair.get_pk());
+                    let keypair = e.to_keypair();
+
+                    // Only for fee, reward is minted by coinbase after claimed.
+                    self.fetch_owned_utxo(provider, &address, &keypair).await?;
+
+                    let core = utxo::Output {
+                        amount: XfrAmount::NonConfidential(e.amount),
+                        asset: FRA.asset_type,
+                        address: address.clone(),
+                        owner_memo: None,
+                        lock: None,
+                    };
+
+                    self.outputs.push(Output {
+                        core,
+                        operation: OutputOperation::ClaimReward(e.to_operation()?),
+                    });
+
+                    self.keypairs.insert(address, keypair);
+                }
             }
         }
 
@@ -302,6 +797,7 @@ impl Builder {
                 .clone(),
             address: Address::blockhole(),
             owner_memo: record.owner_memo.clone(),
+            lock: None,
         };
 
         let output = Output {
@@ -309,14 +805,24 @@ impl Builder {
             operation: OutputOperation::Fee,
         };
 
-        // add a handling fee for each operation initiator
+        // add a handling fee for each operation initiator,
+        // include owners whose outputs are fetched but not selected yet.
         let addr_vec = self
             .mapper
             .inner
-            .iter()
-            .map(|(addr, _)| addr.clone())
-            .collect::<Vec<Address>>();
+            .keys()
+            .chain(self.keypairs.keys())
+            .chain(self.multisigs.keys())
+            .cloned()
+            .collect::<BTreeSet<Address>>();
         for addr in addr_vec.iter() {
+            self.select_utxo(
+                prng,
+                addr,
+                &record.open_asset_record.asset_type,
+                record.open_asset_record.amount,
+            )?;
+
             self.mapper.sub(
                 addr,
                 &record.open_asset_record.asset_type,
@@ -336,11 +842,18 @@ impl Builder {
         log::debug!("Charge is {:?}", mapper_vec);
 
         for (address, asset, amount, confidential_amount, confidential_asset) in mapper_vec {
-            let public_key = self
-                .keypairs
-                .get(&address)
-                .ok_or(Error::BalanceNotEnough)?
-                .get_pk();
+            if amount == 0 {
+                continue;
+            }
+
+            let public_key = if let Some(keypair) = self.keypairs.get(&address) {
+                keypair.get_pk()
+            } else if let Some(multisig) = self.multisigs.get(&address) {
+                // Change of multi-signature owner is non-confidential, public key is a placeholder.
+                *multisig.public_keys.first().ok_or(Error::KeyMustBeSet)?
+            } else {
+                return Err(Error::BalanceNotEnough);
+            };
 
             let record = utils::build_output(
                 prng,
@@ -361,6 +874,7 @@ impl Builder {
                     .clone(),
                 address,
                 owner_memo: record.owner_memo.clone(),
+                lock: None,
             };
             self.outputs.push(Output {
                 core,
@@ -400,9 +914,16 @@ impl Builder {
         };
 
         // signature.
-        let keypairs = self.keypairs.into_values().collect::<Vec<XfrKeyPair>>();
+        let mut signers = self.cosigners;
+        signers.extend(self.keypairs);
+        let keypairs = signers.into_values().collect::<Vec<XfrKeyPair>>();
         tx.signature(&keypairs)?;
 
+        // multi-signature owners are signed by cosigners above.
+        for multisig in self.multisigs.into_values() {
+            tx.signatures.push(Signature::Multi(multisig));
+        }
+
         Ok(tx)
     }
 }
@@ -0,0 +1,46 @@
+use libfindora::Address;
+use rand_core::{CryptoRng, RngCore};
+use serde::{Deserialize, Serialize};
+use zei::xfr::{
+    asset_record::AssetRecordType,
+    sig::XfrKeyPair,
+    structs::{AssetRecord, AssetRecordTemplate, AssetType},
+};
+
+use crate::Result;
+
+/// Destroy asset of keypair, only non-confidential.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Burn {
+    pub keypair: XfrKeyPair,
+
+    pub amount: u64,
+
+    pub asset_type: AssetType,
+}
+
+impl Burn {
+    pub fn to_output_asset_record<R: CryptoRng + RngCore>(
+        &self,
+        prng: &mut R,
+    ) -> Result<AssetRecord> {
+        // Public key is only a placeholder to fit zei.
+        let template = AssetRecordTemplate::with_no_asset_tracing(
+            self.amount,
+            self.asset_type,
+            AssetRecordType::from_flags(false, false),
+            self.keypair.get_pk(),
+        );
+        Ok(AssetRecord::from_template_no_identity_tracing(
+            prng, &template,
+        )?)
+    }
+
+    pub fn to_input_address(&self) -> Address {
+        Address::from(self.keypair.get_pk())
+    }
+
+    pub fn to_keypair(&self) -> XfrKeyPair {
+        self.keypair.clone()
+    }
+}
@@ -0,0 +1,24 @@
+use libfindora::{asset::Amount, rewards, staking::TendermintAddress};
+use serde::{Deserialize, Serialize};
+use zei::xfr::sig::XfrKeyPair;
+
+use crate::Result;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ClaimReward {
+    pub amount: Amount,
+    pub validator: TendermintAddress,
+    pub keypair: XfrKeyPair,
+}
+
+impl ClaimReward {
+    pub fn to_operation(&self) -> Result<rewards::Claim> {
+        let validator = self.validator.clone();
+
+        Ok(rewards::Claim { validator })
+    }
+
+    pub fn to_keypair(&self) -> XfrKeyPair {
+        self.keypair.clone()
+    }
+}
@@ -0,0 +1,22 @@
+use libfindora::{asset::AssetType, Address};
+use serde::{Deserialize, Serialize};
+use zei::xfr::sig::XfrKeyPair;
+
+/// Merge small outputs of an asset into one output.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Consolidate {
+    pub keypair: XfrKeyPair,
+    pub asset_type: AssetType,
+    /// Max inputs of transaction, include the input paying fee.
+    pub max_inputs: usize,
+}
+
+impl Consolidate {
+    pub fn to_keypair(&self) -> XfrKeyPair {
+        self.keypair.clone()
+    }
+
+    pub fn to_input_address(&self) -> Address {
+        Address::from(self.keypair.get_pk())
+    }
+}
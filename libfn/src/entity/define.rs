@@ -11,8 +11,15 @@ use zei::xfr::sig::XfrKeyPair;
 pub struct Define {
     pub maximum: Option<U256>,
     pub transferable: bool,
+    pub freezable: bool,
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+    pub decimals: u8,
+    pub memo: Option<String>,
     pub keypair: XfrKeyPair,
     pub asset: AssetType,
+    /// Derive asset type with owner, see `libfindora::asset::derive_asset_type`.
+    pub code: Vec<u8>,
 }
 
 impl Define {
@@ -23,12 +30,19 @@ impl Define {
             address,
             asset: XfrAssetType::NonConfidential(self.asset),
             owner_memo: None,
+            lock: None,
             amount: XfrAmount::NonConfidential(0),
         };
 
         let asset = AssetMeta {
             maximum: self.maximum,
             transferable: self.transferable,
+            freezable: self.freezable,
+            name: self.name.clone(),
+            symbol: self.symbol.clone(),
+            decimals: self.decimals,
+            memo: self.memo.clone(),
+            code: self.code.clone(),
         };
 
         Output {
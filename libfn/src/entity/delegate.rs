@@ -42,6 +42,7 @@ impl Delegate {
             address,
             memo: None,
             validator: None,
+            profile: None,
         })
     }
 
@@ -49,3 +50,25 @@ impl Delegate {
         self.keypair.clone()
     }
 }
+
+/// Move delegated amount between validators without unbonding.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Redelegate {
+    pub amount: Amount,
+    pub from: TendermintAddress,
+    pub to: TendermintAddress,
+    pub keypair: XfrKeyPair,
+}
+
+impl Redelegate {
+    pub fn to_operation(&self) -> staking::Redelegate {
+        staking::Redelegate {
+            from: self.from.clone(),
+            to: self.to.clone(),
+        }
+    }
+
+    pub fn to_keypair(&self) -> XfrKeyPair {
+        self.keypair.clone()
+    }
+}
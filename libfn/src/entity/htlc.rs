@@ -0,0 +1,108 @@
+use libfindora::{htlc, utxo::OutputId, Address};
+use primitive_types::H256;
+
+use rand_core::{CryptoRng, RngCore};
+use serde::{Deserialize, Serialize};
+
+use zei::xfr::asset_record::AssetRecordType;
+use zei::xfr::structs::AssetType;
+use zei::xfr::{
+    sig::XfrKeyPair,
+    structs::{AssetRecord, AssetRecordTemplate},
+};
+
+use crate::Result;
+
+/// Lock asset to address by hash and time, only non-confidential.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct HashLock {
+    /// Sender, refund to it after deadline.
+    pub keypair: XfrKeyPair,
+
+    /// Receiver, claim it with preimage before deadline.
+    pub address: Address,
+
+    pub amount: u64,
+
+    pub asset_type: AssetType,
+
+    /// SHA-256 of preimage.
+    pub hash: H256,
+
+    /// Block height of deadline.
+    pub deadline: i64,
+}
+
+impl HashLock {
+    pub fn to_output_asset_record<R: CryptoRng + RngCore>(
+        &self,
+        prng: &mut R,
+    ) -> Result<AssetRecord> {
+        // Public key is only a placeholder to fit zei.
+        let template = AssetRecordTemplate::with_no_asset_tracing(
+            self.amount,
+            self.asset_type,
+            AssetRecordType::from_flags(false, false),
+            self.keypair.get_pk(),
+        );
+        Ok(AssetRecord::from_template_no_identity_tracing(
+            prng, &template,
+        )?)
+    }
+
+    pub fn to_input_address(&self) -> Address {
+        Address::from(self.keypair.get_pk())
+    }
+
+    pub fn to_keypair(&self) -> XfrKeyPair {
+        self.keypair.clone()
+    }
+
+    pub fn to_operation(&self) -> htlc::HashLock {
+        htlc::HashLock {
+            hash: self.hash,
+            deadline: self.deadline,
+            sender: self.to_input_address(),
+        }
+    }
+}
+
+/// Claim hash-time-locked output with preimage.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct HashClaim {
+    /// Receiver of hash-time-locked output.
+    pub keypair: XfrKeyPair,
+
+    pub output_id: OutputId,
+
+    pub preimage: Vec<u8>,
+}
+
+impl HashClaim {
+    pub fn to_keypair(&self) -> XfrKeyPair {
+        self.keypair.clone()
+    }
+
+    pub fn to_unlock(&self) -> htlc::Unlock {
+        htlc::Unlock::Claim(self.preimage.clone())
+    }
+}
+
+/// Refund hash-time-locked output after deadline.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct HashRefund {
+    /// Sender of hash-time-locked output.
+    pub keypair: XfrKeyPair,
+
+    pub output_id: OutputId,
+}
+
+impl HashRefund {
+    pub fn to_keypair(&self) -> XfrKeyPair {
+        self.keypair.clone()
+    }
+
+    pub fn to_unlock(&self) -> htlc::Unlock {
+        htlc::Unlock::Refund
+    }
+}
@@ -1,5 +1,8 @@
 use crate::Result;
-use libfindora::asset::{Amount, AssetType};
+use libfindora::{
+    asset::{Amount, AssetType},
+    supply::SupplyOpening,
+};
 use rand_core::{CryptoRng, RngCore};
 use serde::{Deserialize, Serialize};
 use zei::xfr::{
@@ -8,12 +11,22 @@ use zei::xfr::{
     structs::{AssetRecord, AssetRecordTemplate},
 };
 
+/// Prove issued supply stays under maximum.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ProveSupply {
+    /// Opening of supply before this issue.
+    pub opening: SupplyOpening,
+    pub maximum: u64,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Issue {
     pub amount: Amount,
     pub asset_type: AssetType,
     pub confidential_amount: bool,
     pub keypair: XfrKeyPair,
+    /// Required by confidential amount of asset with maximum.
+    pub prove_supply: Option<ProveSupply>,
 }
 
 impl Issue {
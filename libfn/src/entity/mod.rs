@@ -1,5 +1,5 @@
 mod issue;
-pub use issue::Issue;
+pub use issue::{Issue, ProveSupply};
 
 mod transfer;
 pub use transfer::Transfer;
@@ -16,6 +16,24 @@ pub use stake::*;
 mod undelegate;
 pub use undelegate::*;
 
+mod claim;
+pub use claim::ClaimReward;
+
+mod multisig;
+pub use multisig::MultiSigTransfer;
+
+mod htlc;
+pub use htlc::{HashClaim, HashLock, HashRefund};
+
+mod consolidate;
+pub use consolidate::Consolidate;
+
+mod burn;
+pub use burn::Burn;
+
+mod ownership;
+pub use ownership::{ConfigureAsset, FreezeAddress, TransferOwnership};
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -24,6 +42,20 @@ pub enum Entity {
     Issue(Issue),
     Transfer(Transfer),
     Delegate(Delegate),
+    Redelegate(Redelegate),
     Stake(Stake),
     Undelegate(Undelegate),
+    UpdateValidator(UpdateValidator),
+    Unjail(Unjail),
+    CancelUnbonding(CancelUnbonding),
+    ClaimReward(ClaimReward),
+    MultiSigTransfer(MultiSigTransfer),
+    HashLock(HashLock),
+    HashClaim(HashClaim),
+    HashRefund(HashRefund),
+    Consolidate(Consolidate),
+    TransferOwnership(TransferOwnership),
+    ConfigureAsset(ConfigureAsset),
+    FreezeAddress(FreezeAddress),
+    Burn(Burn),
 }
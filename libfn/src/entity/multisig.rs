@@ -0,0 +1,71 @@
+use libfindora::{transaction::MultiSignature, Address};
+
+use rand_core::{CryptoRng, RngCore};
+use serde::{Deserialize, Serialize};
+
+use zei::xfr::asset_record::AssetRecordType;
+use zei::xfr::structs::AssetType;
+use zei::xfr::{
+    sig::{XfrKeyPair, XfrPublicKey},
+    structs::{AssetRecord, AssetRecordTemplate},
+};
+
+use crate::{Error, Result};
+
+/// Transfer from M-of-N multi-signature owner, only non-confidential.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MultiSigTransfer {
+    /// Number of signatures required by owner.
+    pub threshold: u32,
+
+    /// All public keys of owner.
+    pub public_keys: Vec<XfrPublicKey>,
+
+    /// Keypairs sign this transfer, at least `threshold`.
+    pub keypairs: Vec<XfrKeyPair>,
+
+    /// to
+    pub address: Address,
+
+    pub amount: u64,
+
+    pub asset_type: AssetType,
+}
+
+impl MultiSigTransfer {
+    pub fn to_output_asset_record<R: CryptoRng + RngCore>(
+        &self,
+        prng: &mut R,
+    ) -> Result<AssetRecord> {
+        // Public key is only a placeholder to fit zei.
+        let pk = *self.public_keys.first().ok_or(Error::KeyMustBeSet)?;
+
+        let template = AssetRecordTemplate::with_no_asset_tracing(
+            self.amount,
+            self.asset_type,
+            AssetRecordType::from_flags(false, false),
+            pk,
+        );
+        Ok(AssetRecord::from_template_no_identity_tracing(
+            prng, &template,
+        )?)
+    }
+
+    pub fn to_input_address(&self) -> Address {
+        Address::from_multisig(self.threshold, &self.public_keys)
+    }
+
+    pub fn to_multi_signature(&self) -> MultiSignature {
+        MultiSignature::new(self.threshold, self.public_keys.clone())
+    }
+
+    pub fn to_keypairs(&self) -> Result<Vec<XfrKeyPair>> {
+        let threshold: usize = self.threshold.try_into()?;
+
+        if self.keypairs.len() < threshold {
+            return Err(Error::SignatureNotEnough);
+        }
+
+        Ok(self.keypairs.clone())
+    }
+}
@@ -0,0 +1,109 @@
+use libfindora::{
+    asset::{AssetConfig, AssetType, XfrAmount, XfrAssetType},
+    transaction::Output,
+    utxo, Address, OutputOperation,
+};
+use serde::{Deserialize, Serialize};
+use zei::xfr::sig::XfrKeyPair;
+
+/// Transfer ownership of asset, signed by current owner.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct TransferOwnership {
+    /// Current owner.
+    pub keypair: XfrKeyPair,
+    pub asset: AssetType,
+    /// New owner.
+    pub address: Address,
+}
+
+impl TransferOwnership {
+    pub fn to_keypair(&self) -> XfrKeyPair {
+        self.keypair.clone()
+    }
+
+    pub fn to_output(&self) -> Output {
+        let core = utxo::Output {
+            address: self.address.clone(),
+            asset: XfrAssetType::NonConfidential(self.asset),
+            owner_memo: None,
+            lock: None,
+            amount: XfrAmount::NonConfidential(0),
+        };
+
+        Output {
+            core,
+            operation: OutputOperation::TransferOwnership,
+        }
+    }
+}
+
+/// Update configuration of asset, signed by owner.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ConfigureAsset {
+    pub keypair: XfrKeyPair,
+    pub asset: AssetType,
+    pub transferable: bool,
+}
+
+impl ConfigureAsset {
+    pub fn to_keypair(&self) -> XfrKeyPair {
+        self.keypair.clone()
+    }
+
+    pub fn to_output(&self) -> Output {
+        let address = Address::from(self.keypair.get_pk());
+
+        let core = utxo::Output {
+            address,
+            asset: XfrAssetType::NonConfidential(self.asset),
+            owner_memo: None,
+            lock: None,
+            amount: XfrAmount::NonConfidential(0),
+        };
+
+        let config = AssetConfig {
+            transferable: self.transferable,
+        };
+
+        Output {
+            core,
+            operation: OutputOperation::ConfigureAsset(config),
+        }
+    }
+}
+
+/// Freeze or unfreeze address from spending asset, signed by owner.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FreezeAddress {
+    pub keypair: XfrKeyPair,
+    pub asset: AssetType,
+    pub address: Address,
+    /// Unfreeze address if false.
+    pub frozen: bool,
+}
+
+impl FreezeAddress {
+    pub fn to_keypair(&self) -> XfrKeyPair {
+        self.keypair.clone()
+    }
+
+    pub fn to_output(&self) -> Output {
+        let owner = Address::from(self.keypair.get_pk());
+
+        let core = utxo::Output {
+            address: owner,
+            asset: XfrAssetType::NonConfidential(self.asset),
+            owner_memo: None,
+            lock: None,
+            amount: XfrAmount::NonConfidential(0),
+        };
+
+        let operation = if self.frozen {
+            OutputOperation::FreezeAddress(self.address.clone())
+        } else {
+            OutputOperation::UnfreezeAddress(self.address.clone())
+        };
+
+        Output { core, operation }
+    }
+}
@@ -1,6 +1,6 @@
 use libfindora::{
     asset::{Amount, FRA},
-    staking::{self, TendermintAddress, ValidatorPublicKey},
+    staking::{self, TendermintAddress, ValidatorProfile, ValidatorPublicKey},
 };
 use rand_core::{CryptoRng, RngCore};
 use serde::{Deserialize, Serialize};
@@ -17,10 +17,21 @@ pub struct StakeMemo {
     pub name: String,
     pub website: String,
     pub desc: String,
-    // rate * 6,
+    // rate * 10^6,
     pub rate: u64,
 }
 
+impl StakeMemo {
+    pub fn to_profile(&self) -> ValidatorProfile {
+        ValidatorProfile {
+            name: self.name.clone(),
+            website: self.website.clone(),
+            description: self.desc.clone(),
+            commission_rate: self.rate,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Stake {
     pub amount: Amount,
@@ -51,8 +62,9 @@ impl Stake {
 
         Ok(staking::Delegate {
             address,
-            memo: Some(serde_json::to_vec(&self.memo)?),
+            memo: None,
             validator: Some(self.public_key.clone()),
+            profile: Some(self.memo.to_profile()),
         })
     }
 
@@ -60,3 +72,43 @@ impl Stake {
         self.keypair.clone()
     }
 }
+
+/// Return jailed validator staked by keypair to active set.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Unjail {
+    pub validator: TendermintAddress,
+    pub keypair: XfrKeyPair,
+}
+
+impl Unjail {
+    pub fn to_operation(&self) -> staking::Unjail {
+        staking::Unjail {
+            address: self.validator.clone(),
+        }
+    }
+
+    pub fn to_keypair(&self) -> XfrKeyPair {
+        self.keypair.clone()
+    }
+}
+
+/// Update profile of validator staked by keypair.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpdateValidator {
+    pub validator: TendermintAddress,
+    pub keypair: XfrKeyPair,
+    pub memo: StakeMemo,
+}
+
+impl UpdateValidator {
+    pub fn to_operation(&self) -> staking::UpdateValidator {
+        staking::UpdateValidator {
+            address: self.validator.clone(),
+            profile: self.memo.to_profile(),
+        }
+    }
+
+    pub fn to_keypair(&self) -> XfrKeyPair {
+        self.keypair.clone()
+    }
+}
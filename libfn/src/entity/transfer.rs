@@ -1,4 +1,4 @@
-use libfindora::Address;
+use libfindora::{utxo::Lock, Address};
 
 use crate::types;
 
@@ -31,6 +31,9 @@ pub struct Transfer {
     pub confidential_amount: bool,
 
     pub confidential_asset: bool,
+
+    /// Output can't be spent before this lock.
+    pub lock: Option<Lock>,
 }
 
 #[derive(Default)]
@@ -42,6 +45,7 @@ pub struct TransferBuilder<'a> {
     asset_type: &'a str,
     confidential_amount: bool,
     confidential_asset: bool,
+    lock: Option<Lock>,
 }
 
 impl<'a> TransferBuilder<'a> {
@@ -80,6 +84,11 @@ impl<'a> TransferBuilder<'a> {
         self
     }
 
+    pub fn lock(mut self, lock: Option<Lock>) -> TransferBuilder<'a> {
+        self.lock = lock;
+        self
+    }
+
     pub fn build(self) -> Result<Transfer> {
         let mut asset_type: [u8; ASSET_TYPE_LENGTH] = Default::default();
         let b_astyp = base64::decode_config(self.asset_type, base64::URL_SAFE)?;
@@ -93,6 +102,7 @@ impl<'a> TransferBuilder<'a> {
             asset_type: AssetType(asset_type),
             confidential_amount: self.confidential_amount,
             confidential_asset: self.confidential_asset,
+            lock: self.lock,
         })
     }
 }
@@ -45,3 +45,20 @@ impl Undelegate {
         self.keypair.clone()
     }
 }
+
+/// Cancel pending unbonding, amount is delegated back to validator.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CancelUnbonding {
+    pub id: u64,
+    pub keypair: XfrKeyPair,
+}
+
+impl CancelUnbonding {
+    pub fn to_operation(&self) -> staking::CancelUnbonding {
+        staking::CancelUnbonding { id: self.id }
+    }
+
+    pub fn to_keypair(&self) -> XfrKeyPair {
+        self.keypair.clone()
+    }
+}
@@ -26,6 +26,10 @@ pub enum Error {
     DerivationPathError(derivation_path::DerivationPathError),
     AbcfSdkError(String),
     NoResponse,
+    SignatureNotEnough,
+    OutputNotFound,
+    NothingToConsolidate,
+    UnknownCoinSelection(String),
 }
 
 impl Display for Error {
@@ -61,6 +65,10 @@ impl std::error::Error for Error {
             Self::DerivationPathError(_) => "derivation_path error",
             Self::AbcfSdkError(_) => "abcf sdk error",
             Self::NoResponse => "No response from network",
+            Self::SignatureNotEnough => "signature not enough",
+            Self::OutputNotFound => "output not found",
+            Self::NothingToConsolidate => "less than two outputs to consolidate",
+            Self::UnknownCoinSelection(_) => "unknown coin selection strategy",
         }
     }
 }
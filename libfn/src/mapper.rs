@@ -61,6 +61,14 @@ impl Mapper {
         Ok(())
     }
 
+    pub fn balance(&self, address: &Address, asset: &AssetType) -> Amount {
+        self.inner
+            .get(address)
+            .and_then(|v| v.get(asset))
+            .map(|(a, _, _)| *a)
+            .unwrap_or_default()
+    }
+
     pub fn to_vec(&self) -> Vec<(Address, AssetType, Amount, bool, bool)> {
         let mut v = Vec::new();
 
@@ -0,0 +1,106 @@
+use abcf_sdk::providers::Provider;
+use fm_asset::{
+    rpc::{
+        AssetInfoRequest, AssetsRequest, AssetsResponse, MetadataRequest, MetadataResponse,
+        OwnedAssetsRequest, SupplyRequest, SupplyResponse, MAX_ASSETS_LIMIT,
+    },
+    AssetInfo,
+};
+use libfindora::{asset::AssetType, Address};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::net::utils::abci_query;
+use crate::Result;
+
+async fn rpc_query<P: Provider, Req: Serialize, Resp: DeserializeOwned>(
+    provider: &mut P,
+    method: &str,
+    req: &Req,
+) -> Result<Option<Resp>> {
+    let req_bytes = serde_json::to_vec(req)?;
+    let hex_data = format!("0x{}", hex::encode(req_bytes));
+
+    let hex_path = format!("0x{}", hex::encode(format!("rpc/asset/{}", method)));
+
+    let params = serde_json::json!({
+        "path": hex_path,
+        "height": 0i64,
+        "data": hex_data,
+    });
+
+    abci_query::<Resp, P>(params, provider).await
+}
+
+/// Get issued supply and maximum of asset, none if asset isn't defined.
+pub async fn get_supply<P: Provider>(
+    provider: &mut P,
+    asset: &AssetType,
+) -> Result<Option<SupplyResponse>> {
+    rpc_query(provider, "supply", &SupplyRequest { asset: *asset }).await
+}
+
+/// Get on-chain name, symbol, decimals and memo of asset, none if asset isn't defined.
+pub async fn get_metadata<P: Provider>(
+    provider: &mut P,
+    asset: &AssetType,
+) -> Result<Option<MetadataResponse>> {
+    rpc_query(provider, "metadata", &MetadataRequest { asset: *asset }).await
+}
+
+/// Get info of asset, none if asset isn't defined.
+pub async fn get_info<P: Provider>(
+    provider: &mut P,
+    asset: &AssetType,
+) -> Result<Option<AssetInfo>> {
+    rpc_query(provider, "info", &AssetInfoRequest { asset: *asset }).await
+}
+
+/// Get a page of all defined assets.
+pub async fn get_assets_page<P: Provider>(
+    provider: &mut P,
+    cursor: u64,
+    limit: u64,
+) -> Result<AssetsResponse> {
+    let req = AssetsRequest { cursor, limit };
+    Ok(rpc_query(provider, "assets", &req)
+        .await?
+        .unwrap_or_default())
+}
+
+/// Get a page of assets owned by address.
+pub async fn get_owned_assets_page<P: Provider>(
+    provider: &mut P,
+    owner: &Address,
+    cursor: u64,
+    limit: u64,
+) -> Result<AssetsResponse> {
+    let req = OwnedAssetsRequest {
+        owner: owner.clone(),
+        cursor,
+        limit,
+    };
+    Ok(rpc_query(provider, "owned_assets", &req)
+        .await?
+        .unwrap_or_default())
+}
+
+/// Get all assets owned by address.
+pub async fn get_owned_assets<P: Provider>(
+    provider: &mut P,
+    owner: &Address,
+) -> Result<Vec<AssetInfo>> {
+    let mut assets = Vec::new();
+    let mut cursor = 0;
+
+    loop {
+        let page = get_owned_assets_page(provider, owner, cursor, MAX_ASSETS_LIMIT).await?;
+        assets.extend(page.assets);
+
+        match page.next_cursor {
+            Some(c) => cursor = c,
+            None => break,
+        }
+    }
+
+    Ok(assets)
+}
@@ -1,6 +1,9 @@
+pub mod asset;
 pub mod metadata;
 pub mod owned_outputs;
+pub mod rewards;
 pub mod staking;
+pub mod status;
 
 mod tx;
 mod utils;
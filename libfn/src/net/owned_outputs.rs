@@ -1,67 +1,106 @@
 use abcf_sdk::providers::Provider;
+use fm_utxo::rpc::{OwnedOutputsRequest, OwnedOutputsResponse, MAX_OWNED_OUTPUTS_LIMIT};
 use libfindora::{
+    asset::AssetType,
     utxo::{Output, OutputId},
     Address,
 };
-use primitive_types::H512;
 use serde::{Deserialize, Serialize};
-use std::str::FromStr;
 
 use crate::net::utils::abci_query;
 use crate::{Error, Result};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-struct OutputIdTemp {
-    pub txid: String,
-    pub n: u32,
-}
+/// Max slots scanned by one page.
+pub const PAGE_LIMIT: u64 = MAX_OWNED_OUTPUTS_LIMIT;
 
-impl OutputIdTemp {
-    pub fn to_outputid(&self) -> Result<OutputId> {
-        let h512 = H512::from_str(&self.txid).map_err(|_| Error::FraAddressFormatError)?;
-        Ok(OutputId {
-            txid: h512,
-            n: self.n,
-        })
-    }
+pub async fn get<P: Provider>(
+    provider: &mut P,
+    address: &Address,
+) -> Result<(Vec<OutputId>, Vec<Output>)> {
+    get_filtered(provider, address, None, None).await
 }
 
-pub async fn get<P: Provider>(
+/// Get all outputs of address, filter by asset type and confidential.
+pub async fn get_filtered<P: Provider>(
     provider: &mut P,
     address: &Address,
+    asset: Option<AssetType>,
+    confidential: Option<bool>,
 ) -> Result<(Vec<OutputId>, Vec<Output>)> {
     let mut outputid_v = Vec::new();
     let mut output_v = Vec::new();
-    let address_bytes = serde_json::to_vec(address)?;
-    let hex_address = hex::encode(address_bytes);
 
-    let path = format!("stateless/utxo/owned_outputs/0x{}", hex_address);
-    let hex_path = "0x".to_string() + hex::encode(path.as_bytes()).as_str();
+    let mut req = OwnedOutputsRequest {
+        address: address.clone(),
+        cursor: 0,
+        limit: PAGE_LIMIT,
+        asset,
+        confidential,
+    };
+
+    loop {
+        let resp = get_page(provider, &req).await?;
+
+        for o in resp.outputs {
+            outputid_v.push(o.output_id);
+            output_v.push(o.output);
+        }
+
+        match resp.next_cursor {
+            Some(cursor) => req.cursor = cursor,
+            None => break,
+        }
+    }
+
+    Ok((outputid_v, output_v))
+}
+
+/// Get one page of outputs of address.
+pub async fn get_page<P: Provider>(
+    provider: &mut P,
+    req: &OwnedOutputsRequest,
+) -> Result<OwnedOutputsResponse> {
+    let req_bytes = serde_json::to_vec(req)?;
+    let hex_data = format!("0x{}", hex::encode(req_bytes));
+
+    let hex_path = format!("0x{}", hex::encode("rpc/utxo/owned_outputs"));
 
     let params = serde_json::json!({
         "path": hex_path,
         "height": 0i64,
+        "data": hex_data,
     });
 
-    if let Some(output_id_temps) = abci_query::<Vec<OutputIdTemp>, P>(params, provider).await? {
-        for output_id_temp in output_id_temps {
-            let outputid = output_id_temp.to_outputid()?;
-            let outputid_bytes = serde_json::to_vec(&outputid)?;
-            let hex_outputid = hex::encode(outputid_bytes);
-
-            let path = format!("stateful/utxo/outputs_set/0x{}", hex_outputid);
-            let hex_path = "0x".to_string() + hex::encode(path.as_bytes()).as_str();
-
-            let params = serde_json::json!({
-                "path": hex_path,
-                "height": 0_i64,
-            });
-            if let Some(output) = abci_query::<Output, P>(params, provider).await? {
-                outputid_v.push(outputid);
-                output_v.push(output);
-            }
-        }
+    if let Some(resp) = abci_query::<OwnedOutputsResponse, P>(params, provider).await? {
+        Ok(resp)
+    } else {
+        Err(Error::NoResponse)
     }
+}
 
-    Ok((outputid_v, output_v))
+async fn query<T, K, P>(provider: &mut P, prefix: &str, key: &K) -> Result<Option<T>>
+where
+    T: for<'de> Deserialize<'de>,
+    K: Serialize,
+    P: Provider,
+{
+    let key_bytes = serde_json::to_vec(key)?;
+    let hex_key = hex::encode(key_bytes);
+
+    let path = format!("{}/0x{}", prefix, hex_key);
+    let hex_path = "0x".to_string() + hex::encode(path.as_bytes()).as_str();
+
+    let params = serde_json::json!({
+        "path": hex_path,
+        "height": 0i64,
+    });
+
+    abci_query::<T, P>(params, provider).await
+}
+
+pub async fn get_output<P: Provider>(
+    provider: &mut P,
+    output_id: &OutputId,
+) -> Result<Option<Output>> {
+    query::<Output, _, P>(provider, "stateful/utxo/outputs_set", output_id).await
 }
@@ -0,0 +1,35 @@
+use abcf_sdk::providers::Provider;
+use fm_rewards::rpc::{RewardsRequest, RewardsResponse};
+use libfindora::{staking::TendermintAddress, Address};
+
+use crate::net::utils::abci_query;
+use crate::{Error, Result};
+
+/// Get rewards accrued by delegator from validator.
+pub async fn get_rewards<P: Provider>(
+    provider: &mut P,
+    validator: TendermintAddress,
+    delegator: Address,
+) -> Result<RewardsResponse> {
+    let req = RewardsRequest {
+        validator,
+        delegator,
+    };
+
+    let req_bytes = serde_json::to_vec(&req)?;
+    let hex_data = format!("0x{}", hex::encode(req_bytes));
+
+    let hex_path = format!("0x{}", hex::encode("rpc/rewards/rewards"));
+
+    let params = serde_json::json!({
+        "path": hex_path,
+        "height": 0i64,
+        "data": hex_data,
+    });
+
+    if let Some(resp) = abci_query::<RewardsResponse, P>(params, provider).await? {
+        Ok(resp)
+    } else {
+        Err(Error::NoResponse)
+    }
+}
@@ -1,9 +1,15 @@
 use crate::net::utils::abci_query;
 use crate::{Error, Result};
 use abcf_sdk::providers::Provider;
+use fm_staking::rpc::{
+    DelegatorUnbondingRequest, JailedRequest, JailedResponse, UnbondingRequest, UnbondingResponse,
+};
 use libfindora::asset::Amount;
-use libfindora::staking::{TendermintAddress, ValidatorPublicKey};
+use libfindora::staking::{
+    TendermintAddress, UnbondingEntry, ValidatorProfile, ValidatorPublicKey,
+};
 use libfindora::Address;
+use serde::{de::DeserializeOwned, Serialize};
 use std::collections::BTreeMap;
 
 pub async fn get_validator_pubkey<P: Provider>(
@@ -50,6 +56,25 @@ pub async fn get_validator_staker<P: Provider>(
     }
 }
 
+/// Get name, website, description and commission rate of validator.
+pub async fn get_validator_profile<P: Provider>(
+    provider: &mut P,
+    addr: TendermintAddress,
+) -> Result<Option<ValidatorProfile>> {
+    let addr_bytes = serde_json::to_vec(&addr)?;
+    let hex_addr = hex::encode(addr_bytes);
+
+    let path = format!("stateful/staking/validator_profile/0x{}", hex_addr);
+    let hex_path = format!("0x{}", hex::encode(path));
+
+    let params = serde_json::json!({
+        "path": hex_path,
+        "height": 0i64,
+    });
+
+    abci_query::<ValidatorProfile, P>(params, provider).await
+}
+
 pub async fn get_global_power<P: Provider>(provider: &mut P) -> Result<u64> {
     let path = format!("stateful/staking/global_power/0x{}", hex::encode(""));
     let hex_path = format!("0x{}", hex::encode(path));
@@ -125,3 +150,54 @@ pub async fn get_power<P: Provider>(provider: &mut P, addr: TendermintAddress) -
         Err(Error::NoResponse)
     }
 }
+
+async fn rpc_query<P: Provider, Req: Serialize, Resp: DeserializeOwned>(
+    provider: &mut P,
+    method: &str,
+    req: &Req,
+) -> Result<Option<Resp>> {
+    let req_bytes = serde_json::to_vec(req)?;
+    let hex_data = format!("0x{}", hex::encode(req_bytes));
+
+    let hex_path = format!("0x{}", hex::encode(format!("rpc/staking/{}", method)));
+
+    let params = serde_json::json!({
+        "path": hex_path,
+        "height": 0i64,
+        "data": hex_data,
+    });
+
+    abci_query::<Resp, P>(params, provider).await
+}
+
+/// Get unbonding entry by id.
+pub async fn get_unbonding<P: Provider>(provider: &mut P, id: u64) -> Result<UnbondingEntry> {
+    rpc_query(provider, "unbonding", &UnbondingRequest { id })
+        .await?
+        .ok_or(Error::NoResponse)
+}
+
+/// Get unbonding entries of delegator with id.
+pub async fn get_delegator_unbonding<P: Provider>(
+    provider: &mut P,
+    delegator: Address,
+) -> Result<Vec<(u64, UnbondingEntry)>> {
+    let resp: Option<UnbondingResponse> = rpc_query(
+        provider,
+        "delegator_unbonding",
+        &DelegatorUnbondingRequest { delegator },
+    )
+    .await?;
+
+    Ok(resp.unwrap_or_default().entries)
+}
+
+/// Get jailed status of validator.
+pub async fn get_jailed<P: Provider>(
+    provider: &mut P,
+    validator: TendermintAddress,
+) -> Result<JailedResponse> {
+    rpc_query(provider, "jailed", &JailedRequest { validator })
+        .await?
+        .ok_or(Error::NoResponse)
+}
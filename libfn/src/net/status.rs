@@ -0,0 +1,23 @@
+use abcf_sdk::providers::Provider;
+
+use crate::net::utils::abci_query;
+use crate::Result;
+
+/// Get height and timestamp which locked outputs are checked with, same as mempool does.
+pub async fn get<P: Provider>(provider: &mut P) -> Result<(i64, i64)> {
+    let path = "stateful/utxo/last_block/0x";
+    let hex_path = format!("0x{}", hex::encode(path));
+
+    let params = serde_json::json!({
+        "path": hex_path,
+        "height": 0i64,
+    });
+
+    // Before first block, nothing is stored.
+    let (height, timestamp) = abci_query::<(i64, i64), P>(params, provider)
+        .await?
+        .unwrap_or_default();
+
+    // Transaction sent now will be packed into next block.
+    Ok((height + 1, timestamp))
+}
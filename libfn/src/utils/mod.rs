@@ -1,7 +1,10 @@
 pub mod mnemonic;
 
 mod outputs;
-pub use outputs::{build_output, open_outputs};
+pub use outputs::{build_output, open_outputs, unlocked_outputs};
 
 mod fee;
 pub use fee::build_fee;
+
+mod select;
+pub use select::{select_coins, select_consolidation, CoinSelection};
@@ -1,6 +1,6 @@
 use libfindora::{
     asset::{Amount, AssetType},
-    utxo::Output,
+    utxo::{Output, OutputId},
 };
 use rand_core::{CryptoRng, RngCore};
 use zei::xfr::{
@@ -30,6 +30,22 @@ pub fn open_outputs(outputs: Vec<Output>, keypair: &XfrKeyPair) -> Result<Vec<As
     Ok(ars)
 }
 
+/// Skip outputs which are still locked at `height` and `timestamp`.
+pub fn unlocked_outputs(
+    ids: Vec<OutputId>,
+    outputs: Vec<Output>,
+    height: i64,
+    timestamp: i64,
+) -> (Vec<OutputId>, Vec<Output>) {
+    ids.into_iter()
+        .zip(outputs)
+        .filter(|(_, output)| match &output.lock {
+            Some(lock) => lock.is_unlocked(height, timestamp),
+            None => true,
+        })
+        .unzip()
+}
+
 pub fn build_output<R: RngCore + CryptoRng>(
     prng: &mut R,
     asset: AssetType,
@@ -0,0 +1,332 @@
+use fm_fee::FRA_FEE_AMOUNT;
+use libfindora::{
+    asset::{Amount, AssetType, FRA},
+    utxo::OutputId,
+};
+use rand::seq::SliceRandom;
+use rand_core::RngCore;
+use std::str::FromStr;
+use zei::xfr::structs::AssetRecord;
+
+use crate::{Error, Result};
+
+/// Max branches tried by branch-and-bound before fallback.
+const BNB_MAX_TRIES: usize = 100_000;
+
+/// Strategy to pick inputs from owned outputs of one asset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoinSelection {
+    /// Spend all outputs of the asset.
+    All,
+    /// Spend largest outputs first, fewest inputs.
+    LargestFirst,
+    /// Search outputs summing to the target exactly, no change is left.
+    /// Fallback to largest-first if not found.
+    BranchAndBound,
+    /// Spend outputs in random order, so outputs aren't linked by size.
+    Random,
+}
+
+impl Default for CoinSelection {
+    fn default() -> Self {
+        Self::LargestFirst
+    }
+}
+
+impl FromStr for CoinSelection {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "all" => Ok(Self::All),
+            "largest-first" => Ok(Self::LargestFirst),
+            "branch-and-bound" => Ok(Self::BranchAndBound),
+            "random" => Ok(Self::Random),
+            _ => Err(Error::UnknownCoinSelection(s.to_string())),
+        }
+    }
+}
+
+/// Pick outputs from `candidates` to cover `target`, picked ones are removed.
+///
+/// All candidates are picked if they are not enough.
+pub fn select_coins<R: RngCore>(
+    strategy: CoinSelection,
+    prng: &mut R,
+    candidates: &mut Vec<(OutputId, AssetRecord)>,
+    target: Amount,
+) -> Vec<(OutputId, AssetRecord)> {
+    match strategy {
+        CoinSelection::All => candidates.drain(..).collect(),
+        CoinSelection::LargestFirst => {
+            candidates.sort_by_key(|(_, ar)| std::cmp::Reverse(ar.open_asset_record.amount));
+            take_until(candidates, target)
+        }
+        CoinSelection::BranchAndBound => {
+            candidates.sort_by_key(|(_, ar)| std::cmp::Reverse(ar.open_asset_record.amount));
+
+            let amounts = candidates
+                .iter()
+                .map(|(_, ar)| ar.open_asset_record.amount)
+                .collect::<Vec<Amount>>();
+
+            match branch_and_bound(&amounts, target) {
+                Some(picked) => {
+                    let mut selected = Vec::new();
+                    // Remove from back, so indexes are kept.
+                    for index in picked.into_iter().rev() {
+                        selected.push(candidates.remove(index));
+                    }
+                    selected
+                }
+                None => take_until(candidates, target),
+            }
+        }
+        CoinSelection::Random => {
+            candidates.shuffle(prng);
+            take_until(candidates, target)
+        }
+    }
+}
+
+fn take_until(
+    candidates: &mut Vec<(OutputId, AssetRecord)>,
+    target: Amount,
+) -> Vec<(OutputId, AssetRecord)> {
+    let mut sum: Amount = 0;
+    let mut count = 0;
+
+    for (_, ar) in candidates.iter() {
+        if sum >= target {
+            break;
+        }
+        sum = sum.saturating_add(ar.open_asset_record.amount);
+        count += 1;
+    }
+
+    candidates.drain(..count).collect()
+}
+
+/// Depth first search of indexes of `amounts` (descending) summing to `target`.
+fn branch_and_bound(amounts: &[Amount], target: Amount) -> Option<Vec<usize>> {
+    // Sum of amounts after each index, bound of remaining branches.
+    let mut remaining = vec![0u128; amounts.len() + 1];
+    for i in (0..amounts.len()).rev() {
+        remaining[i] = remaining[i + 1] + amounts[i] as u128;
+    }
+
+    let mut picked = Vec::new();
+    let mut tries = 0;
+
+    if search(
+        amounts,
+        &remaining,
+        0,
+        target as u128,
+        &mut picked,
+        &mut tries,
+    ) {
+        Some(picked)
+    } else {
+        None
+    }
+}
+
+fn search(
+    amounts: &[Amount],
+    remaining: &[u128],
+    index: usize,
+    target: u128,
+    picked: &mut Vec<usize>,
+    tries: &mut usize,
+) -> bool {
+    if target == 0 {
+        return true;
+    }
+
+    *tries += 1;
+
+    if index >= amounts.len() || remaining[index] < target || *tries > BNB_MAX_TRIES {
+        return false;
+    }
+
+    let amount = amounts[index] as u128;
+
+    if amount <= target {
+        picked.push(index);
+        if search(
+            amounts,
+            remaining,
+            index + 1,
+            target - amount,
+            picked,
+            tries,
+        ) {
+            return true;
+        }
+        picked.pop();
+    }
+
+    search(amounts, remaining, index + 1, target, picked, tries)
+}
+
+/// Pick inputs to merge outputs of `asset` into one output.
+///
+/// The largest FRA output is kept to pay the fee, then the smallest outputs
+/// of `asset` are picked until `max_inputs` is reached. Outputs of
+/// confidential amount are skipped.
+pub fn select_consolidation(
+    ids: Vec<OutputId>,
+    ars: Vec<AssetRecord>,
+    asset: &AssetType,
+    max_inputs: usize,
+) -> Result<(Vec<OutputId>, Vec<AssetRecord>)> {
+    let mut candidates = Vec::new();
+    let mut fee: Option<(OutputId, AssetRecord)> = None;
+
+    for (id, ar) in ids.into_iter().zip(ars) {
+        let oar = &ar.open_asset_record;
+
+        // Merged output is non-confidential, it would reveal amount.
+        if oar.blind_asset_record.amount.is_confidential() {
+            continue;
+        }
+
+        if oar.asset_type == FRA.bare_asset_type
+            && oar.amount >= FRA_FEE_AMOUNT
+            && fee
+                .as_ref()
+                .map(|(_, f)| f.open_asset_record.amount < oar.amount)
+                .unwrap_or(true)
+        {
+            if let Some((fid, far)) = fee.replace((id, ar)) {
+                if &FRA.bare_asset_type == asset {
+                    candidates.push((fid, far));
+                }
+            }
+            continue;
+        }
+
+        if &oar.asset_type == asset {
+            candidates.push((id, ar));
+        }
+    }
+
+    let (fee_id, fee_ar) = fee.ok_or(Error::BalanceNotEnough)?;
+
+    // Fee output is merged too if it's the asset.
+    let merged_fee = &FRA.bare_asset_type == asset;
+
+    candidates.sort_by_key(|(_, ar)| ar.open_asset_record.amount);
+    candidates.truncate(max_inputs.saturating_sub(1));
+
+    let merged = candidates.len() + usize::from(merged_fee);
+    if merged < 2 {
+        return Err(Error::NothingToConsolidate);
+    }
+
+    let (mut ids, mut ars): (Vec<_>, Vec<_>) = candidates.into_iter().unzip();
+    ids.push(fee_id);
+    ars.push(fee_ar);
+
+    Ok((ids, ars))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use primitive_types::H512;
+    use rand_chacha::ChaChaRng;
+    use rand_core::SeedableRng;
+    use zei::xfr::{asset_record::AssetRecordType, sig::XfrKeyPair, structs::AssetRecordTemplate};
+
+    fn candidates(prng: &mut ChaChaRng, amounts: &[Amount]) -> Vec<(OutputId, AssetRecord)> {
+        let keypair = XfrKeyPair::generate(prng);
+
+        amounts
+            .iter()
+            .enumerate()
+            .map(|(n, amount)| {
+                let template = AssetRecordTemplate::with_no_asset_tracing(
+                    *amount,
+                    FRA.bare_asset_type,
+                    AssetRecordType::from_flags(false, false),
+                    keypair.get_pk(),
+                );
+                let ar = AssetRecord::from_template_no_identity_tracing(prng, &template).unwrap();
+                let id = OutputId {
+                    txid: H512::zero(),
+                    n: n as u32,
+                };
+                (id, ar)
+            })
+            .collect()
+    }
+
+    fn sum(selected: &[(OutputId, AssetRecord)]) -> Amount {
+        selected
+            .iter()
+            .map(|(_, ar)| ar.open_asset_record.amount)
+            .sum()
+    }
+
+    #[test]
+    fn test_branch_and_bound_exact_match() {
+        let mut prng = ChaChaRng::from_seed([0u8; 32]);
+        let mut pool = candidates(&mut prng, &[10, 7, 5, 3]);
+
+        let selected = select_coins(CoinSelection::BranchAndBound, &mut prng, &mut pool, 12);
+
+        assert_eq!(sum(&selected), 12);
+        assert_eq!(selected.len() + pool.len(), 4);
+    }
+
+    #[test]
+    fn test_branch_and_bound_change() {
+        let mut prng = ChaChaRng::from_seed([0u8; 32]);
+        let mut pool = candidates(&mut prng, &[10, 6, 4]);
+
+        // No exact match, fallback to largest first.
+        let selected = select_coins(CoinSelection::BranchAndBound, &mut prng, &mut pool, 13);
+
+        assert_eq!(sum(&selected), 16);
+        assert_eq!(pool.len(), 1);
+    }
+
+    #[test]
+    fn test_largest_first_change() {
+        let mut prng = ChaChaRng::from_seed([0u8; 32]);
+        let mut pool = candidates(&mut prng, &[1, 9, 5]);
+
+        let selected = select_coins(CoinSelection::LargestFirst, &mut prng, &mut pool, 12);
+
+        assert_eq!(sum(&selected), 14);
+        assert_eq!(sum(&pool), 1);
+    }
+
+    #[test]
+    fn test_insufficient_funds() {
+        let mut prng = ChaChaRng::from_seed([0u8; 32]);
+
+        for strategy in [
+            CoinSelection::All,
+            CoinSelection::LargestFirst,
+            CoinSelection::BranchAndBound,
+            CoinSelection::Random,
+        ] {
+            let mut pool = candidates(&mut prng, &[4, 3, 2]);
+
+            let selected = select_coins(strategy, &mut prng, &mut pool, 100);
+
+            assert_eq!(sum(&selected), 9);
+            assert!(pool.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_branch_and_bound_search() {
+        assert_eq!(branch_and_bound(&[8, 5, 4, 1], 9), Some(vec![0, 3]));
+        assert_eq!(branch_and_bound(&[8, 5, 4, 1], 0), Some(vec![]));
+        assert_eq!(branch_and_bound(&[8, 5], 4), None);
+    }
+}
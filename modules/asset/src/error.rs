@@ -1,4 +1,5 @@
 use libfindora::{asset::AssetType, Address};
+use primitive_types::U256;
 
 #[derive(Debug)]
 pub enum Error {
@@ -9,6 +10,16 @@ pub enum Error {
     MustBeNonConfidentialAsset,
     IssueMustBeOwner(Address, Address),
     AssetCantTransfer(AssetType),
+    ExceedMaximum(AssetType, U256, U256),
+    OverflowAdd,
+    MustSignedByOwner(AssetType, Address),
+    AssetNotFreezable(AssetType),
+    AddressFrozen(AssetType, Address),
+    InvalidMetadata(AssetType, String),
+    AssetTypeMismatch(AssetType, AssetType),
+    MustProveSupply(AssetType),
+    InvalidSupplyProof(AssetType, String),
+    ConfidentialAssetFrozen(Address),
     Unknown,
 }
 
@@ -36,6 +47,56 @@ impl From<Error> for abcf::Error {
             Error::AssetCantTransfer(e) => {
                 abcf::Error::ABCIApplicationError(90005, format!("asset {:?} can't transfer", e))
             }
+            Error::ExceedMaximum(e, issued, maximum) => abcf::Error::ABCIApplicationError(
+                90006,
+                format!(
+                    "asset {:?} issued {} will exceed maximum {}",
+                    e, issued, maximum
+                ),
+            ),
+            Error::OverflowAdd => {
+                abcf::Error::ABCIApplicationError(90007, String::from("issued supply overflow"))
+            }
+            Error::MustSignedByOwner(e, owner) => abcf::Error::ABCIApplicationError(
+                90008,
+                format!("asset {:?} must be signed by owner {:?}", e, owner),
+            ),
+            Error::AssetNotFreezable(e) => {
+                abcf::Error::ABCIApplicationError(90009, format!("asset {:?} isn't freezable", e))
+            }
+            Error::AddressFrozen(e, address) => abcf::Error::ABCIApplicationError(
+                90010,
+                format!("address {:?} is frozen for asset {:?}", address, e),
+            ),
+            Error::InvalidMetadata(e, reason) => abcf::Error::ABCIApplicationError(
+                90011,
+                format!("asset {:?} has invalid metadata: {}", e, reason),
+            ),
+            Error::AssetTypeMismatch(e, expected) => abcf::Error::ABCIApplicationError(
+                90012,
+                format!(
+                    "asset {:?} isn't derived from owner and code, expected {:?}",
+                    e, expected
+                ),
+            ),
+            Error::MustProveSupply(e) => abcf::Error::ABCIApplicationError(
+                90013,
+                format!(
+                    "asset {:?} has confidential supply, issue must prove supply",
+                    e
+                ),
+            ),
+            Error::InvalidSupplyProof(e, reason) => abcf::Error::ABCIApplicationError(
+                90014,
+                format!("asset {:?} has invalid supply proof: {}", e, reason),
+            ),
+            Error::ConfidentialAssetFrozen(address) => abcf::Error::ABCIApplicationError(
+                90015,
+                format!(
+                    "address {:?} is frozen for some asset, can't spend confidential asset type",
+                    address
+                ),
+            ),
             Error::MustBeNonConfidentialAsset => abcf::Error::ABCIApplicationError(
                 80008,
                 String::from("mustbe nonconfidential asset type."),
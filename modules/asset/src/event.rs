@@ -0,0 +1,22 @@
+use libfindora::Address;
+use serde::{Deserialize, Serialize};
+
+use crate::AssetBurn;
+
+/// Asset is burned.
+#[derive(Clone, Debug, Deserialize, Serialize, abcf::Event)]
+pub struct BurnEvent {
+    pub address: Address,
+    pub asset: String,
+    pub amount: u64,
+}
+
+impl BurnEvent {
+    pub fn new(burn: &AssetBurn) -> Self {
+        Self {
+            address: burn.address.clone(),
+            asset: base64::encode_config(burn.asset.0, base64::URL_SAFE),
+            amount: burn.amount,
+        }
+    }
+}
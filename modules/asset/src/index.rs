@@ -0,0 +1,207 @@
+use abcf::bs3::{MapStore, ValueStore};
+use libfindora::{asset::AssetType, Address};
+
+use crate::{
+    rpc::{AssetsResponse, MAX_ASSETS_LIMIT},
+    AssetInfo, AssetOwnership, Error, Result, Transaction,
+};
+
+/// Stores of asset module's listing index.
+///
+/// All assets are stored in slots `0..count` by defined order. Assets of an
+/// owner are stored in slots `0..owned_count`, removed asset is replaced by the
+/// last one, so both can be read by page.
+///
+/// Assets defined before index is introduced are backfilled the first time
+/// a transaction uses them.
+pub struct AssetIndex<'a, L, C, O, OC, OI> {
+    pub list: &'a mut L,
+    pub count: &'a mut C,
+    pub owned: &'a mut O,
+    pub owned_count: &'a mut OC,
+    pub owned_index: &'a mut OI,
+}
+
+impl<'a, L, C, O, OC, OI> AssetIndex<'a, L, C, O, OC, OI>
+where
+    L: MapStore<u64, AssetType>,
+    C: ValueStore<u64>,
+    O: MapStore<(Address, u64), AssetType>,
+    OC: MapStore<Address, u64>,
+    OI: MapStore<AssetType, (Address, u64)>,
+{
+    fn push_owned(&mut self, owner: &Address, asset: AssetType) -> Result<()> {
+        let count = self.owned_count.get(owner)?.map(|c| *c).unwrap_or_default();
+
+        self.owned.insert((owner.clone(), count), asset)?;
+        self.owned_index.insert(asset, (owner.clone(), count))?;
+        self.owned_count.insert(owner.clone(), count + 1)?;
+
+        Ok(())
+    }
+
+    fn remove_owned(&mut self, asset: &AssetType) -> Result<()> {
+        // Asset defined before index is introduced.
+        let (owner, slot) = match self.owned_index.remove(asset)? {
+            Some(v) => v,
+            None => return Ok(()),
+        };
+
+        let last = self
+            .owned_count
+            .get(&owner)?
+            .map(|c| *c)
+            .unwrap_or_default()
+            .checked_sub(1)
+            .ok_or(Error::AssetTypeNotExists(*asset))?;
+
+        let moved = self
+            .owned
+            .remove(&(owner.clone(), last))?
+            .ok_or(Error::AssetTypeNotExists(*asset))?;
+
+        if slot != last {
+            self.owned.insert((owner.clone(), slot), moved)?;
+            self.owned_index.insert(moved, (owner.clone(), slot))?;
+        }
+
+        if last == 0 {
+            self.owned_count.remove(&owner)?;
+        } else {
+            self.owned_count.insert(owner, last)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn define(&mut self, info: &AssetInfo) -> Result<()> {
+        let count = self.count.get()?.map(|c| *c).unwrap_or_default();
+
+        self.list.insert(count, info.asset)?;
+        self.count.set(count + 1)?;
+
+        self.push_owned(&info.owner, info.asset)
+    }
+
+    pub fn transfer(&mut self, ownership: &AssetOwnership) -> Result<()> {
+        self.remove_owned(&ownership.asset)?;
+        self.push_owned(&ownership.owner, ownership.asset)
+    }
+
+    /// Index asset defined before index is introduced.
+    pub fn backfill(
+        &mut self,
+        asset_infos: &impl MapStore<AssetType, AssetInfo>,
+        asset: &AssetType,
+    ) -> Result<()> {
+        if self.owned_index.get(asset)?.is_some() {
+            return Ok(());
+        }
+
+        if let Some(info) = asset_infos.get(asset)? {
+            let info = info.clone();
+            self.define(&info)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn apply(
+        &mut self,
+        asset_infos: &impl MapStore<AssetType, AssetInfo>,
+        tx: &Transaction,
+    ) -> Result<()> {
+        for info in &tx.define_asset {
+            self.define(info)?;
+        }
+
+        let used = tx
+            .transfer_asset
+            .iter()
+            .chain(tx.issue_asset.iter().map(|v| &v.asset))
+            .chain(tx.transfer_ownership.iter().map(|v| &v.asset))
+            .chain(tx.configure_asset.iter().map(|v| &v.asset))
+            .chain(tx.freeze_asset.iter().map(|v| &v.asset))
+            .chain(tx.burn_asset.iter().map(|v| &v.asset));
+
+        for asset in used {
+            self.backfill(asset_infos, asset)?;
+        }
+
+        for ownership in &tx.transfer_ownership {
+            self.transfer(ownership)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn page_infos(
+    asset_infos: &impl MapStore<AssetType, AssetInfo>,
+    assets: Vec<AssetType>,
+    cursor: u64,
+    limit: u64,
+    total: u64,
+) -> Result<AssetsResponse> {
+    let mut res = AssetsResponse::default();
+
+    for asset in assets {
+        if let Some(info) = asset_infos.get(&asset)? {
+            res.assets.push(info.clone());
+        }
+    }
+
+    let next = cursor.saturating_add(limit);
+    if next < total {
+        res.next_cursor = Some(next);
+    }
+
+    Ok(res)
+}
+
+/// Page of all defined assets.
+pub fn query_assets(
+    asset_infos: &impl MapStore<AssetType, AssetInfo>,
+    list: &impl MapStore<u64, AssetType>,
+    count: &impl ValueStore<u64>,
+    cursor: u64,
+    limit: u64,
+) -> Result<AssetsResponse> {
+    // Zero limit never moves cursor forward.
+    let limit = limit.clamp(1, MAX_ASSETS_LIMIT);
+    let total = count.get()?.map(|c| *c).unwrap_or_default();
+    let end = cursor.saturating_add(limit).min(total);
+
+    let mut assets = Vec::new();
+    for slot in cursor..end {
+        if let Some(asset) = list.get(&slot)? {
+            assets.push(*asset);
+        }
+    }
+
+    page_infos(asset_infos, assets, cursor, limit, total)
+}
+
+/// Page of assets owned by address.
+pub fn query_owned_assets(
+    asset_infos: &impl MapStore<AssetType, AssetInfo>,
+    owned: &impl MapStore<(Address, u64), AssetType>,
+    owned_count: &impl MapStore<Address, u64>,
+    owner: &Address,
+    cursor: u64,
+    limit: u64,
+) -> Result<AssetsResponse> {
+    // Zero limit never moves cursor forward.
+    let limit = limit.clamp(1, MAX_ASSETS_LIMIT);
+    let total = owned_count.get(owner)?.map(|c| *c).unwrap_or_default();
+    let end = cursor.saturating_add(limit).min(total);
+
+    let mut assets = Vec::new();
+    for slot in cursor..end {
+        if let Some(asset) = owned.get(&(owner.clone(), slot))? {
+            assets.push(*asset);
+        }
+    }
+
+    page_infos(asset_infos, assets, cursor, limit, total)
+}
@@ -6,7 +6,16 @@ pub use module::AssetModule;
 mod error;
 pub use error::{Error, Result};
 
-mod utils;
+pub mod utils;
+
+mod index;
+
+mod event;
+pub use event::BurnEvent;
+
+pub mod rpc;
 
 mod transaction;
-pub use transaction::{AssetInfo, AssetIssue, Transaction};
+pub use transaction::{
+    AssetBurn, AssetConfigure, AssetFreeze, AssetInfo, AssetIssue, AssetOwnership, Transaction,
+};
@@ -4,23 +4,132 @@ use abcf::{
         model::{Map, Value},
     },
     module::types::{RequestCheckTx, RequestDeliverTx, ResponseCheckTx, ResponseDeliverTx},
-    Application, TxnContext,
+    Application, RPCContext, RPCResponse, TxnContext,
 };
-use libfindora::asset::AssetType;
+use fm_utxo::UtxoModule;
+use libfindora::{asset::AssetType, Address};
+use primitive_types::{H256, U256};
 
-use crate::{utils, AssetInfo, Transaction};
+use crate::{index, rpc, utils, AssetInfo, BurnEvent, Transaction};
 
 #[abcf::module(name = "asset", version = 1, impl_version = "0.1.1", target_height = 0)]
+#[dependence(utxo = "UtxoModule")]
 pub struct AssetModule {
     #[stateful(merkle = "AppendOnlyMerkle")]
     pub asset_infos: Map<AssetType, AssetInfo>,
-    // Only a placeholder, will remove when abcf update.
+    /// Non-confidential amount issued of asset.
+    #[stateful(merkle = "AppendOnlyMerkle")]
+    pub issued: Map<AssetType, U256>,
+    /// Commitment to confidential amount issued of asset, see `libfindora::supply`.
+    #[stateful(merkle = "AppendOnlyMerkle")]
+    pub supply_commitment: Map<AssetType, H256>,
+    /// Amount burned of asset.
+    #[stateful(merkle = "AppendOnlyMerkle")]
+    pub burned: Map<AssetType, U256>,
+    /// Addresses frozen from spending asset.
+    #[stateful(merkle = "AppendOnlyMerkle")]
+    pub frozen: Map<(AssetType, Address), bool>,
+    /// Number of assets address is frozen from, checked for confidential asset type.
+    #[stateful(merkle = "AppendOnlyMerkle")]
+    pub frozen_count: Map<Address, u64>,
+    /// Slots of all defined assets, see `index::AssetIndex`.
+    #[stateless]
+    pub asset_list: Map<u64, AssetType>,
+    #[stateless]
+    pub asset_count: Value<u64>,
+    /// Slots of assets owned by address.
+    #[stateless]
+    pub owned_assets: Map<(Address, u64), AssetType>,
     #[stateless]
-    pub sl_value: Value<u32>,
+    pub owned_assets_count: Map<Address, u64>,
+    #[stateless]
+    pub owned_assets_index: Map<AssetType, (Address, u64)>,
 }
 
 #[abcf::rpcs]
-impl AssetModule {}
+impl AssetModule {
+    pub async fn supply<'a>(
+        &mut self,
+        ctx: &mut RPCContext<'a, Self>,
+        params: rpc::SupplyRequest,
+    ) -> RPCResponse<rpc::SupplyResponse> {
+        let res = utils::get_supply(
+            &ctx.stateful.asset_infos,
+            &ctx.stateful.issued,
+            &ctx.stateful.supply_commitment,
+            &ctx.stateful.burned,
+            &params.asset,
+        );
+
+        match res {
+            Ok(r) => RPCResponse::new(r),
+            Err(e) => abcf::Error::from(e).into(),
+        }
+    }
+
+    pub async fn info<'a>(
+        &mut self,
+        ctx: &mut RPCContext<'a, Self>,
+        params: rpc::AssetInfoRequest,
+    ) -> RPCResponse<AssetInfo> {
+        match utils::get_info(&ctx.stateful.asset_infos, &params.asset) {
+            Ok(r) => RPCResponse::new(r),
+            Err(e) => abcf::Error::from(e).into(),
+        }
+    }
+
+    /// All defined assets, paginated by cursor.
+    pub async fn assets<'a>(
+        &mut self,
+        ctx: &mut RPCContext<'a, Self>,
+        params: rpc::AssetsRequest,
+    ) -> RPCResponse<rpc::AssetsResponse> {
+        let res = index::query_assets(
+            &ctx.stateful.asset_infos,
+            &ctx.stateless.asset_list,
+            &ctx.stateless.asset_count,
+            params.cursor,
+            params.limit,
+        );
+
+        match res {
+            Ok(r) => RPCResponse::new(r),
+            Err(e) => abcf::Error::from(e).into(),
+        }
+    }
+
+    /// Assets owned by address, paginated by cursor.
+    pub async fn owned_assets<'a>(
+        &mut self,
+        ctx: &mut RPCContext<'a, Self>,
+        params: rpc::OwnedAssetsRequest,
+    ) -> RPCResponse<rpc::AssetsResponse> {
+        let res = index::query_owned_assets(
+            &ctx.stateful.asset_infos,
+            &ctx.stateless.owned_assets,
+            &ctx.stateless.owned_assets_count,
+            &params.owner,
+            params.cursor,
+            params.limit,
+        );
+
+        match res {
+            Ok(r) => RPCResponse::new(r),
+            Err(e) => abcf::Error::from(e).into(),
+        }
+    }
+
+    pub async fn metadata<'a>(
+        &mut self,
+        ctx: &mut RPCContext<'a, Self>,
+        params: rpc::MetadataRequest,
+    ) -> RPCResponse<rpc::MetadataResponse> {
+        match utils::get_metadata(&ctx.stateful.asset_infos, &params.asset) {
+            Ok(r) => RPCResponse::new(r),
+            Err(e) => abcf::Error::from(e).into(),
+        }
+    }
+}
 
 /// Module's block logic.
 #[abcf::application]
@@ -34,9 +143,45 @@ impl Application for AssetModule {
     ) -> abcf::Result<ResponseCheckTx> {
         let tx = &req.tx;
 
-        utils::check_define(&mut context.stateful.asset_infos, &tx.define_asset)?;
-        utils::check_issue(&context.stateful.asset_infos, &tx.issue_asset)?;
+        let (height, _) = fm_utxo::utils::last_block(&context.deps.utxo.stateful.last_block)?;
+
+        utils::check_define(
+            &mut context.stateful.asset_infos,
+            &tx.signers,
+            height == 0,
+            &tx.define_asset,
+        )?;
+        utils::check_issue(
+            &context.stateful.asset_infos,
+            &mut context.stateful.issued,
+            &mut context.stateful.supply_commitment,
+            &tx.issue_asset,
+        )?;
         utils::check_transfer(&context.stateful.asset_infos, &tx.transfer_asset)?;
+        utils::check_transfer_ownership(
+            &mut context.stateful.asset_infos,
+            &tx.signers,
+            &tx.transfer_ownership,
+        )?;
+        utils::check_configure(
+            &mut context.stateful.asset_infos,
+            &tx.signers,
+            &tx.configure_asset,
+        )?;
+        utils::check_frozen_inputs(
+            &context.stateful.frozen,
+            &context.stateful.frozen_count,
+            &context.deps.utxo.stateful.outputs_set,
+            &tx.inputs,
+        )?;
+        utils::check_freeze(
+            &context.stateful.asset_infos,
+            &mut context.stateful.frozen,
+            &mut context.stateful.frozen_count,
+            &tx.signers,
+            &tx.freeze_asset,
+        )?;
+        utils::check_burn(&mut context.stateful.burned, &tx.burn_asset)?;
 
         Ok(Default::default())
     }
@@ -49,9 +194,58 @@ impl Application for AssetModule {
     ) -> abcf::Result<ResponseDeliverTx> {
         let tx = &req.tx;
 
-        utils::check_define(&mut context.stateful.asset_infos, &tx.define_asset)?;
-        utils::check_issue(&context.stateful.asset_infos, &tx.issue_asset)?;
+        let (height, _) = fm_utxo::utils::last_block(&context.deps.utxo.stateful.last_block)?;
+
+        utils::check_define(
+            &mut context.stateful.asset_infos,
+            &tx.signers,
+            height == 0,
+            &tx.define_asset,
+        )?;
+        utils::check_issue(
+            &context.stateful.asset_infos,
+            &mut context.stateful.issued,
+            &mut context.stateful.supply_commitment,
+            &tx.issue_asset,
+        )?;
         utils::check_transfer(&context.stateful.asset_infos, &tx.transfer_asset)?;
+        utils::check_transfer_ownership(
+            &mut context.stateful.asset_infos,
+            &tx.signers,
+            &tx.transfer_ownership,
+        )?;
+        utils::check_configure(
+            &mut context.stateful.asset_infos,
+            &tx.signers,
+            &tx.configure_asset,
+        )?;
+        utils::check_frozen_inputs(
+            &context.stateful.frozen,
+            &context.stateful.frozen_count,
+            &context.deps.utxo.stateful.outputs_set,
+            &tx.inputs,
+        )?;
+        utils::check_freeze(
+            &context.stateful.asset_infos,
+            &mut context.stateful.frozen,
+            &mut context.stateful.frozen_count,
+            &tx.signers,
+            &tx.freeze_asset,
+        )?;
+        utils::check_burn(&mut context.stateful.burned, &tx.burn_asset)?;
+
+        let mut asset_index = index::AssetIndex {
+            list: &mut context.stateless.asset_list,
+            count: &mut context.stateless.asset_count,
+            owned: &mut context.stateless.owned_assets,
+            owned_count: &mut context.stateless.owned_assets_count,
+            owned_index: &mut context.stateless.owned_assets_index,
+        };
+        asset_index.apply(&context.stateful.asset_infos, tx)?;
+
+        for burn in &tx.burn_asset {
+            context.events.emit(&BurnEvent::new(burn))?;
+        }
 
         Ok(Default::default())
     }
@@ -0,0 +1,70 @@
+use libfindora::{asset::AssetType, Address};
+use primitive_types::{H256, U256};
+use serde::{Deserialize, Serialize};
+
+use crate::AssetInfo;
+
+/// Maximum assets returned in one page.
+pub const MAX_ASSETS_LIMIT: u64 = 1000;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SupplyRequest {
+    pub asset: AssetType,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SupplyResponse {
+    pub asset: AssetType,
+    /// Non-confidential amount issued.
+    pub issued: U256,
+    pub burned: U256,
+    /// Issued minus burned.
+    pub circulating: U256,
+    pub maximum: Option<U256>,
+    /// Commitment to confidential amount issued, only the owner can open it.
+    pub supply_commitment: Option<H256>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MetadataRequest {
+    pub asset: AssetType,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MetadataResponse {
+    pub asset: AssetType,
+    pub name: Option<String>,
+    pub symbol: Option<String>,
+    /// Decimal places to render amount.
+    pub decimals: u8,
+    pub memo: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AssetInfoRequest {
+    pub asset: AssetType,
+}
+
+/// Page of all defined assets by defined order.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AssetsRequest {
+    pub cursor: u64,
+    /// Clamped to `1..=MAX_ASSETS_LIMIT`.
+    pub limit: u64,
+}
+
+/// Page of assets owned by address.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OwnedAssetsRequest {
+    pub owner: Address,
+    pub cursor: u64,
+    /// Clamped to `1..=MAX_ASSETS_LIMIT`.
+    pub limit: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct AssetsResponse {
+    pub assets: Vec<AssetInfo>,
+    /// Cursor of next page, none if no more assets.
+    pub next_cursor: Option<u64>,
+}
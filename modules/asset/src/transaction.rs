@@ -1,8 +1,12 @@
+use std::collections::BTreeSet;
+
 use libfindora::{
-    asset::{AssetType, XfrAmount, XfrAssetType},
+    asset::{AssetConfig, AssetType, XfrAmount, XfrAssetType, FRA},
+    supply::SupplyProof,
+    utxo::OutputId,
     Address,
 };
-use primitive_types::U256;
+use primitive_types::{H512, U256};
 use serde::{Deserialize, Serialize};
 
 use crate::Error;
@@ -13,6 +17,23 @@ pub struct AssetInfo {
     pub transferable: bool,
     pub asset: AssetType,
     pub owner: Address,
+    #[serde(default)]
+    pub freezable: bool,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub symbol: Option<String>,
+    #[serde(default = "default_decimals")]
+    pub decimals: u8,
+    #[serde(default)]
+    pub memo: Option<String>,
+    /// Chosen by owner to derive asset type, empty for legacy asset.
+    #[serde(default)]
+    pub code: Vec<u8>,
+}
+
+fn default_decimals() -> u8 {
+    FRA.decimals
 }
 
 #[derive(Debug)]
@@ -20,6 +41,34 @@ pub struct AssetIssue {
     pub asset: AssetType,
     pub amount: XfrAmount,
     pub address: Address,
+    /// Supply stays under maximum, required by confidential amount.
+    pub proof: Option<SupplyProof>,
+}
+
+#[derive(Debug)]
+pub struct AssetOwnership {
+    pub asset: AssetType,
+    pub owner: Address,
+}
+
+#[derive(Debug)]
+pub struct AssetConfigure {
+    pub asset: AssetType,
+    pub config: AssetConfig,
+}
+
+#[derive(Debug)]
+pub struct AssetFreeze {
+    pub asset: AssetType,
+    pub address: Address,
+    pub frozen: bool,
+}
+
+#[derive(Debug)]
+pub struct AssetBurn {
+    pub asset: AssetType,
+    pub amount: u64,
+    pub address: Address,
 }
 
 #[derive(Debug, Default)]
@@ -27,6 +76,13 @@ pub struct Transaction {
     pub define_asset: Vec<AssetInfo>,
     pub transfer_asset: Vec<AssetType>,
     pub issue_asset: Vec<AssetIssue>,
+    pub transfer_ownership: Vec<AssetOwnership>,
+    pub configure_asset: Vec<AssetConfigure>,
+    pub freeze_asset: Vec<AssetFreeze>,
+    pub burn_asset: Vec<AssetBurn>,
+    /// Outputs spent, checked for frozen owner.
+    pub inputs: Vec<OutputId>,
+    pub signers: BTreeSet<Address>,
 }
 
 impl TryFrom<&libfindora::Transaction> for Transaction {
@@ -36,10 +92,15 @@ impl TryFrom<&libfindora::Transaction> for Transaction {
         let mut infos = Vec::new();
         let mut types = Vec::new();
         let mut issue = Vec::new();
+        let mut ownerships = Vec::new();
+        let mut configs = Vec::new();
+        let mut freezes = Vec::new();
+        let mut burns = Vec::new();
 
         for output in &t.outputs {
             match &output.operation {
-                libfindora::OutputOperation::TransferAsset => {
+                libfindora::OutputOperation::TransferAsset
+                | libfindora::OutputOperation::HashLock(_) => {
                     if let XfrAssetType::NonConfidential(at) = output.core.asset {
                         types.push(at);
                     }
@@ -51,6 +112,12 @@ impl TryFrom<&libfindora::Transaction> for Transaction {
                             transferable: e.transferable,
                             asset,
                             owner: output.core.address.clone(),
+                            freezable: e.freezable,
+                            name: e.name.clone(),
+                            symbol: e.symbol.clone(),
+                            decimals: e.decimals,
+                            memo: e.memo.clone(),
+                            code: e.code.clone(),
                         };
 
                         infos.push(info);
@@ -64,6 +131,7 @@ impl TryFrom<&libfindora::Transaction> for Transaction {
                             asset,
                             amount: output.core.amount.clone(),
                             address: output.core.address.clone(),
+                            proof: None,
                         };
 
                         issue.push(info);
@@ -71,14 +139,117 @@ impl TryFrom<&libfindora::Transaction> for Transaction {
                         return Err(Error::MustBeNonConfidentialAsset.into());
                     }
                 }
+                libfindora::OutputOperation::IssueAssetWithProof(proof) => {
+                    if let XfrAssetType::NonConfidential(asset) = output.core.asset {
+                        let info = AssetIssue {
+                            asset,
+                            amount: output.core.amount.clone(),
+                            address: output.core.address.clone(),
+                            proof: Some(proof.clone()),
+                        };
+
+                        issue.push(info);
+                    } else {
+                        return Err(Error::MustBeNonConfidentialAsset.into());
+                    }
+                }
+                libfindora::OutputOperation::TransferOwnership => {
+                    if let XfrAssetType::NonConfidential(asset) = output.core.asset {
+                        ownerships.push(AssetOwnership {
+                            asset,
+                            owner: output.core.address.clone(),
+                        });
+                    } else {
+                        return Err(Error::MustBeNonConfidentialAsset.into());
+                    }
+                }
+                libfindora::OutputOperation::ConfigureAsset(e) => {
+                    if let XfrAssetType::NonConfidential(asset) = output.core.asset {
+                        configs.push(AssetConfigure {
+                            asset,
+                            config: e.clone(),
+                        });
+                    } else {
+                        return Err(Error::MustBeNonConfidentialAsset.into());
+                    }
+                }
+                libfindora::OutputOperation::FreezeAddress(address)
+                | libfindora::OutputOperation::UnfreezeAddress(address) => {
+                    if let XfrAssetType::NonConfidential(asset) = output.core.asset {
+                        let frozen = matches!(
+                            output.operation,
+                            libfindora::OutputOperation::FreezeAddress(_)
+                        );
+
+                        freezes.push(AssetFreeze {
+                            asset,
+                            address: address.clone(),
+                            frozen,
+                        });
+                    } else {
+                        return Err(Error::MustBeNonConfidentialAsset.into());
+                    }
+                }
+                libfindora::OutputOperation::Burn => {
+                    let asset = match output.core.asset {
+                        XfrAssetType::NonConfidential(asset) => asset,
+                        _ => return Err(Error::MustBeNonConfidentialAsset.into()),
+                    };
+
+                    let amount = match output.core.amount {
+                        XfrAmount::NonConfidential(amount) => amount,
+                        _ => return Err(Error::MustBeNonConfidentialAmount.into()),
+                    };
+
+                    burns.push(AssetBurn {
+                        asset,
+                        amount,
+                        address: output.core.address.clone(),
+                    });
+                }
                 _ => {}
             }
         }
 
+        // Inputs minted in this transaction don't have owner yet.
+        let inputs = t
+            .inputs
+            .iter()
+            .filter(|i| {
+                i.txid != H512::zero()
+                    && matches!(
+                        i.operation,
+                        libfindora::InputOperation::TransferAsset
+                            | libfindora::InputOperation::HashUnlock(_)
+                    )
+            })
+            .map(|i| OutputId {
+                txid: i.txid,
+                n: i.n,
+            })
+            .collect();
+
+        // Only define and ownership operations need signers.
+        let signers = if infos.is_empty()
+            && ownerships.is_empty()
+            && configs.is_empty()
+            && freezes.is_empty()
+        {
+            BTreeSet::new()
+        } else {
+            t.verify()?
+        };
+
         Ok(Transaction {
             define_asset: infos,
             transfer_asset: types,
             issue_asset: issue,
+            transfer_ownership: ownerships,
+            configure_asset: configs,
+            freeze_asset: freezes,
+            burn_asset: burns,
+            inputs,
+            signers,
         })
     }
 }
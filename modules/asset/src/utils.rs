@@ -1,13 +1,89 @@
+use std::collections::BTreeSet;
+
 use abcf::bs3::MapStore;
-use libfindora::asset::AssetType;
+use libfindora::{
+    asset::{derive_asset_type, AssetType, XfrAssetType, FRA},
+    supply::{verify_supply, SupplyProof},
+    utxo::{Output, OutputId},
+    Address,
+};
+use primitive_types::{H256, U256};
+
+use crate::{
+    rpc::{MetadataResponse, SupplyResponse},
+    AssetBurn, AssetConfigure, AssetFreeze, AssetInfo, AssetIssue, AssetOwnership, Error, Result,
+};
+
+pub const MAX_NAME_LENGTH: usize = 64;
+pub const MAX_SYMBOL_LENGTH: usize = 16;
+pub const MAX_DECIMALS: u8 = 18;
+pub const MAX_MEMO_LENGTH: usize = 256;
+pub const MAX_CODE_LENGTH: usize = 64;
+
+fn check_metadata(define: &AssetInfo) -> Result<()> {
+    let invalid = |reason: &str| Err(Error::InvalidMetadata(define.asset, String::from(reason)));
+
+    if let Some(name) = &define.name {
+        if name.is_empty() || name.len() > MAX_NAME_LENGTH {
+            return invalid("name length out of range");
+        }
+    }
+
+    if let Some(symbol) = &define.symbol {
+        if symbol.is_empty() || symbol.len() > MAX_SYMBOL_LENGTH {
+            return invalid("symbol length out of range");
+        }
+    }
+
+    if define.decimals > MAX_DECIMALS {
+        return invalid("decimals out of range");
+    }
+
+    if let Some(memo) = &define.memo {
+        if memo.len() > MAX_MEMO_LENGTH {
+            return invalid("memo too long");
+        }
+    }
+
+    Ok(())
+}
+
+/// Asset type must be derived from owner and code, except FRA defined in genesis.
+fn check_asset_type(define: &AssetInfo, genesis: bool) -> Result<()> {
+    if genesis && define.asset == FRA.bare_asset_type {
+        return Ok(());
+    }
+
+    if define.code.is_empty() || define.code.len() > MAX_CODE_LENGTH {
+        return Err(Error::InvalidMetadata(
+            define.asset,
+            String::from("code length out of range"),
+        ));
+    }
 
-use crate::{AssetInfo, AssetIssue, Error, Result};
+    let expected = derive_asset_type(&define.owner, &define.code);
+    if define.asset != expected {
+        return Err(Error::AssetTypeMismatch(define.asset, expected));
+    }
 
+    Ok(())
+}
+
+/// Define assets, `genesis` is true before first block.
 pub fn check_define(
     asset_infos: &mut impl MapStore<AssetType, AssetInfo>,
+    signers: &BTreeSet<Address>,
+    genesis: bool,
     tx: &[AssetInfo],
 ) -> Result<()> {
     for define in tx {
+        if !signers.contains(&define.owner) {
+            return Err(Error::MustSignedByOwner(define.asset, define.owner.clone()));
+        }
+
+        check_metadata(define)?;
+        check_asset_type(define, genesis)?;
+
         if asset_infos.get(&define.asset)?.is_some() {
             return Err(Error::AssetTypeAlreadyExists(define.asset));
         }
@@ -17,8 +93,49 @@ pub fn check_define(
     Ok(())
 }
 
+/// Verify supply proof of issue, update issued amount and supply commitment.
+fn check_proved_issue(
+    issued: &mut impl MapStore<AssetType, U256>,
+    supply_commitment: &mut impl MapStore<AssetType, H256>,
+    issue: &AssetIssue,
+    proof: &SupplyProof,
+    maximum: Option<U256>,
+) -> Result<()> {
+    let invalid = |reason: String| Error::InvalidSupplyProof(issue.asset, reason);
+
+    let maximum = maximum.ok_or_else(|| invalid(String::from("asset has no maximum")))?;
+    if maximum > U256::from(u64::MAX) {
+        return Err(invalid(String::from("maximum is larger than u64")));
+    }
+
+    let current = issued.get(&issue.asset)?.map(|v| *v).unwrap_or_default();
+    let commitment = supply_commitment.get(&issue.asset)?.map(|v| *v);
+
+    let new_commitment = verify_supply(
+        proof,
+        current.low_u64(),
+        commitment.as_ref(),
+        &issue.amount,
+        maximum.low_u64(),
+    )
+    .map_err(|e| invalid(format!("{:?}", e)))?;
+
+    if let Some(amount) = issue.amount.get_amount() {
+        let total = current
+            .checked_add(U256::from(amount))
+            .ok_or(Error::OverflowAdd)?;
+        issued.insert(issue.asset, total)?;
+    } else {
+        supply_commitment.insert(issue.asset, new_commitment)?;
+    }
+
+    Ok(())
+}
+
 pub fn check_issue(
     asset_infos: &impl MapStore<AssetType, AssetInfo>,
+    issued: &mut impl MapStore<AssetType, U256>,
+    supply_commitment: &mut impl MapStore<AssetType, H256>,
     tx: &[AssetIssue],
 ) -> Result<()> {
     for issue in tx {
@@ -30,12 +147,36 @@ pub fn check_issue(
                 ));
             }
 
-            // If confidnetal amount and have maximum, failed.
+            if let Some(proof) = &issue.proof {
+                check_proved_issue(issued, supply_commitment, issue, proof, info.maximum)?;
+                continue;
+            }
+
+            // Issued confidential amount is only known by proof.
+            if info.maximum.is_some() && supply_commitment.get(&issue.asset)?.is_some() {
+                return Err(Error::MustProveSupply(issue.asset));
+            }
+
+            // If confidnetal amount and have maximum, must prove supply.
             if info.maximum.is_some() && issue.amount.is_confidential() {
-                return Err(Error::MustBeNonConfidentialAmount);
+                return Err(Error::MustProveSupply(issue.asset));
             }
 
-            // TODO: check maximum.
+            // Supply of confidential amount is unknown, only counts non-confidential.
+            if let Some(amount) = issue.amount.get_amount() {
+                let current = issued.get(&issue.asset)?.map(|v| *v).unwrap_or_default();
+                let total = current
+                    .checked_add(U256::from(amount))
+                    .ok_or(Error::OverflowAdd)?;
+
+                if let Some(maximum) = info.maximum {
+                    if total > maximum {
+                        return Err(Error::ExceedMaximum(issue.asset, total, maximum));
+                    }
+                }
+
+                issued.insert(issue.asset, total)?;
+            }
         } else {
             return Err(Error::AssetTypeNotExists(issue.asset));
         }
@@ -60,3 +201,188 @@ pub fn check_transfer(
 
     Ok(())
 }
+
+pub fn check_transfer_ownership(
+    asset_infos: &mut impl MapStore<AssetType, AssetInfo>,
+    signers: &BTreeSet<Address>,
+    tx: &[AssetOwnership],
+) -> Result<()> {
+    for ownership in tx {
+        let info = asset_infos
+            .get_mut(&ownership.asset)?
+            .ok_or(Error::AssetTypeNotExists(ownership.asset))?;
+
+        if !signers.contains(&info.owner) {
+            return Err(Error::MustSignedByOwner(
+                ownership.asset,
+                info.owner.clone(),
+            ));
+        }
+
+        info.owner = ownership.owner.clone();
+    }
+
+    Ok(())
+}
+
+pub fn check_configure(
+    asset_infos: &mut impl MapStore<AssetType, AssetInfo>,
+    signers: &BTreeSet<Address>,
+    tx: &[AssetConfigure],
+) -> Result<()> {
+    for configure in tx {
+        let info = asset_infos
+            .get_mut(&configure.asset)?
+            .ok_or(Error::AssetTypeNotExists(configure.asset))?;
+
+        if !signers.contains(&info.owner) {
+            return Err(Error::MustSignedByOwner(
+                configure.asset,
+                info.owner.clone(),
+            ));
+        }
+
+        info.transferable = configure.config.transferable;
+    }
+
+    Ok(())
+}
+
+pub fn check_freeze(
+    asset_infos: &impl MapStore<AssetType, AssetInfo>,
+    frozen: &mut impl MapStore<(AssetType, Address), bool>,
+    frozen_count: &mut impl MapStore<Address, u64>,
+    signers: &BTreeSet<Address>,
+    tx: &[AssetFreeze],
+) -> Result<()> {
+    for freeze in tx {
+        let info = asset_infos
+            .get(&freeze.asset)?
+            .ok_or(Error::AssetTypeNotExists(freeze.asset))?;
+
+        if !info.freezable {
+            return Err(Error::AssetNotFreezable(freeze.asset));
+        }
+
+        if !signers.contains(&info.owner) {
+            return Err(Error::MustSignedByOwner(freeze.asset, info.owner.clone()));
+        }
+
+        let key = (freeze.asset, freeze.address.clone());
+        let count = frozen_count
+            .get(&freeze.address)?
+            .map(|v| *v)
+            .unwrap_or_default();
+
+        if freeze.frozen {
+            if frozen.get(&key)?.is_none() {
+                frozen.insert(key, true)?;
+                frozen_count.insert(freeze.address.clone(), count + 1)?;
+            }
+        } else if frozen.remove(&key)?.is_some() {
+            if count > 1 {
+                frozen_count.insert(freeze.address.clone(), count - 1)?;
+            } else {
+                frozen_count.remove(&freeze.address)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Frozen address can't spend outputs of asset.
+///
+/// Asset of confidential asset type is unknown, so address frozen for any
+/// asset can't spend it.
+pub fn check_frozen_inputs(
+    frozen: &impl MapStore<(AssetType, Address), bool>,
+    frozen_count: &impl MapStore<Address, u64>,
+    outputs_set: &impl MapStore<OutputId, Output>,
+    inputs: &[OutputId],
+) -> Result<()> {
+    for input in inputs {
+        // Missing output is reported by utxo module.
+        if let Some(output) = outputs_set.get(input)? {
+            match output.asset {
+                XfrAssetType::NonConfidential(asset) => {
+                    if frozen.get(&(asset, output.address.clone()))?.is_some() {
+                        return Err(Error::AddressFrozen(asset, output.address.clone()));
+                    }
+                }
+                XfrAssetType::Confidential(_) => {
+                    if frozen_count.get(&output.address)?.is_some() {
+                        return Err(Error::ConfidentialAssetFrozen(output.address.clone()));
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+pub fn check_burn(burned: &mut impl MapStore<AssetType, U256>, tx: &[AssetBurn]) -> Result<()> {
+    for burn in tx {
+        let current = burned.get(&burn.asset)?.map(|v| *v).unwrap_or_default();
+        let total = current
+            .checked_add(U256::from(burn.amount))
+            .ok_or(Error::OverflowAdd)?;
+
+        burned.insert(burn.asset, total)?;
+    }
+
+    Ok(())
+}
+
+pub fn get_info(
+    asset_infos: &impl MapStore<AssetType, AssetInfo>,
+    asset: &AssetType,
+) -> Result<AssetInfo> {
+    let info = asset_infos
+        .get(asset)?
+        .ok_or(Error::AssetTypeNotExists(*asset))?;
+
+    Ok(info.clone())
+}
+
+pub fn get_metadata(
+    asset_infos: &impl MapStore<AssetType, AssetInfo>,
+    asset: &AssetType,
+) -> Result<MetadataResponse> {
+    let info = asset_infos
+        .get(asset)?
+        .ok_or(Error::AssetTypeNotExists(*asset))?;
+
+    Ok(MetadataResponse {
+        asset: *asset,
+        name: info.name.clone(),
+        symbol: info.symbol.clone(),
+        decimals: info.decimals,
+        memo: info.memo.clone(),
+    })
+}
+
+pub fn get_supply(
+    asset_infos: &impl MapStore<AssetType, AssetInfo>,
+    issued: &impl MapStore<AssetType, U256>,
+    supply_commitment: &impl MapStore<AssetType, H256>,
+    burned: &impl MapStore<AssetType, U256>,
+    asset: &AssetType,
+) -> Result<SupplyResponse> {
+    let info = asset_infos
+        .get(asset)?
+        .ok_or(Error::AssetTypeNotExists(*asset))?;
+
+    let issued = issued.get(asset)?.map(|v| *v).unwrap_or_default();
+    let burned = burned.get(asset)?.map(|v| *v).unwrap_or_default();
+
+    Ok(SupplyResponse {
+        asset: *asset,
+        issued,
+        burned,
+        circulating: issued.saturating_sub(burned),
+        maximum: info.maximum,
+        supply_commitment: supply_commitment.get(asset)?.map(|v| *v),
+    })
+}
@@ -4,6 +4,7 @@ use std::num::TryFromIntError;
 pub enum Error {
     TryFromIntError(TryFromIntError),
     Bs3Error(abcf::bs3::Error),
+    UtxoError(fm_utxo::Error),
 }
 
 impl From<abcf::bs3::Error> for Error {
@@ -12,6 +13,12 @@ impl From<abcf::bs3::Error> for Error {
     }
 }
 
+impl From<fm_utxo::Error> for Error {
+    fn from(e: fm_utxo::Error) -> Self {
+        Self::UtxoError(e)
+    }
+}
+
 impl From<TryFromIntError> for Error {
     fn from(e: TryFromIntError) -> Self {
         Self::TryFromIntError(e)
@@ -25,6 +32,7 @@ impl From<Error> for abcf::Error {
                 abcf::Error::ABCIApplicationError(80005, format!("{:?}", e))
             }
             Error::Bs3Error(e) => abcf::Error::ABCIApplicationError(80005, format!("{:?}", e)),
+            Error::UtxoError(e) => e.into(),
         }
     }
 }
@@ -0,0 +1,25 @@
+use libfindora::{
+    utxo::{Output, OutputId},
+    Address,
+};
+use serde::{Deserialize, Serialize};
+
+/// Pending output is released into utxo at target height.
+#[derive(Clone, Debug, Deserialize, Serialize, abcf::Event)]
+pub struct ReleaseEvent {
+    pub address: Address,
+    pub output_id: OutputId,
+    pub amount: Option<u64>,
+    pub height: i64,
+}
+
+impl ReleaseEvent {
+    pub fn new(output_id: OutputId, output: &Output, height: i64) -> Self {
+        Self {
+            address: output.address.clone(),
+            output_id,
+            amount: output.amount.get_amount(),
+            height,
+        }
+    }
+}
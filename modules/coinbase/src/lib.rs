@@ -6,6 +6,9 @@ pub use module::CoinbaseModule;
 mod error;
 pub use error::{Error, Result};
 
+mod event;
+pub use event::ReleaseEvent;
+
 mod transaction;
 pub use transaction::Transaction;
 
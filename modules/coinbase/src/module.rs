@@ -8,7 +8,7 @@ use abcf::{
 };
 use fm_utxo::UtxoModule;
 
-use crate::{types::OutputChain, Transaction};
+use crate::{types::OutputChain, utils, ReleaseEvent, Result, Transaction};
 
 #[abcf::module(
     name = "coinbase",
@@ -20,8 +20,12 @@ use crate::{types::OutputChain, Transaction};
 pub struct CoinbaseModule {
     pub block_height: i64,
 
+    /// Outputs pending until height, see `utils::mint`.
     #[stateful(merkle = "AppendOnlyMerkle")]
     pub pending_outputs: Map<i64, OutputChain>,
+    /// Last negative slot of chained pending outputs.
+    #[stateful(merkle = "AppendOnlyMerkle")]
+    pub pending_slot: Value<i64>,
     // Only a placeholder, will remove when abcf update.
     #[stateless]
     pub sl_value: Value<u32>,
@@ -37,7 +41,7 @@ impl Application for CoinbaseModule {
 
     async fn begin_block(
         &mut self,
-        _context: &mut abcf::AppContext<'_, Self>,
+        context: &mut abcf::AppContext<'_, Self>,
         req: &abcf::tm_protos::abci::RequestBeginBlock,
     ) {
         if let Some(header) = &req.header {
@@ -46,6 +50,14 @@ impl Application for CoinbaseModule {
             // TODO: consider panic node.
             panic!("Got none header, Please restart node.");
         }
+
+        // Pending outputs are only released at their height, can't retry later.
+        if let Err(e) = self.release_pending(context) {
+            panic!(
+                "release pending outputs failed: {:?}, Please restart node.",
+                e
+            );
+        }
     }
 
     async fn end_block(
@@ -61,9 +73,18 @@ impl Application for CoinbaseModule {
         context: &mut TxnContext<'_, Self>,
         req: &RequestDeliverTx<Self::Transaction>,
     ) -> abcf::Result<ResponseDeliverTx> {
+        let utxo = &mut context.deps.utxo;
+
+        let mut owned_outputs = fm_utxo::utils::OwnedOutputs {
+            outputs: &mut utxo.stateless.owned_outputs,
+            counts: &mut utxo.stateless.owned_outputs_count,
+            index: &mut utxo.stateless.owned_outputs_index,
+            legacy: &mut utxo.stateless.legacy_owned_outputs,
+        };
+
         fm_utxo::utils::mint(
-            &mut context.deps.utxo.stateful.outputs_set,
-            &mut context.deps.utxo.stateless.owned_outputs,
+            &mut utxo.stateful.outputs_set,
+            &mut owned_outputs,
             &req.tx.outputs,
         )?;
 
@@ -73,4 +94,37 @@ impl Application for CoinbaseModule {
 
 /// Module's methods.
 #[abcf::methods]
-impl CoinbaseModule {}
+impl CoinbaseModule {
+    /// Move outputs pending at current height into utxo.
+    pub fn release_pending(&mut self, context: &mut abcf::AppContext<'_, Self>) -> Result<()> {
+        let height = self.block_height;
+
+        let released = utils::pending(height, &context.stateful.pending_outputs)?;
+
+        let utxo = &mut context.deps.utxo;
+
+        let mut owned_outputs = fm_utxo::utils::OwnedOutputs {
+            outputs: &mut utxo.stateless.owned_outputs,
+            counts: &mut utxo.stateless.owned_outputs_count,
+            index: &mut utxo.stateless.owned_outputs_index,
+            legacy: &mut utxo.stateless.legacy_owned_outputs,
+        };
+
+        fm_utxo::utils::mint(
+            &mut utxo.stateful.outputs_set,
+            &mut owned_outputs,
+            &released,
+        )?;
+
+        utils::release(height, &mut context.stateful.pending_outputs)?;
+
+        for (output_id, output) in released {
+            let event = ReleaseEvent::new(output_id, &output, height);
+            if let Err(e) = context.events.emit(&event) {
+                log::error!("emit release event failed: {:?}", e);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -23,7 +23,11 @@ fn inner(tx: &libfindora::Transaction) -> Result<Transaction, Error> {
     for i in 0..tx.outputs.len() {
         let output = &tx.outputs[i];
 
-        if let libfindora::OutputOperation::IssueAsset = output.operation {
+        if matches!(
+            output.operation,
+            libfindora::OutputOperation::IssueAsset
+                | libfindora::OutputOperation::IssueAssetWithProof(_)
+        ) {
             let output_id = OutputId {
                 txid: tx.txid,
                 n: i.try_into()?,
@@ -4,5 +4,6 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct OutputChain {
     pub output: Output,
+    /// Key of next output at same height, `0` if none.
     pub next: i64,
 }
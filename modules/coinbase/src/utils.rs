@@ -1,14 +1,38 @@
-use abcf::bs3::MapStore;
-use libfindora::utxo::Output;
+use abcf::bs3::{MapStore, ValueStore};
+use digest::Digest;
+use libfindora::utxo::{Output, OutputId};
+use primitive_types::H512;
+use sha3::Sha3_512;
 
 use crate::{types::OutputChain, Result};
 
+/// Pending outputs released at height are given ids of this txid.
+pub fn coinbase_txid(height: i64) -> H512 {
+    let mut hasher = Sha3_512::new();
+    hasher.update(b"coinbase");
+    hasher.update(height.to_be_bytes());
+    H512::from_slice(&hasher.finalize())
+}
+
+/// Pend output until target height.
+///
+/// Head of outputs at target height is stored at key of height, the previous
+/// head is moved to a new negative slot and linked by `next`, `0` ends chain.
 pub fn mint(
     target_height: i64,
     output: Output,
     pending_outputs: &mut impl MapStore<i64, OutputChain>,
+    pending_slot: &mut impl ValueStore<i64>,
 ) -> Result<()> {
-    let next = 0;
+    let next = match pending_outputs.remove(&target_height)? {
+        Some(head) => {
+            let slot = pending_slot.get()?.map(|v| *v).unwrap_or_default() - 1;
+            pending_slot.set(slot)?;
+            pending_outputs.insert(slot, head)?;
+            slot
+        }
+        None => 0,
+    };
 
     let oc = OutputChain { output, next };
 
@@ -16,3 +40,52 @@ pub fn mint(
 
     Ok(())
 }
+
+/// Outputs pending at height, ids are deterministic by minted order.
+///
+/// They are kept until `release` after minted into utxo.
+pub fn pending(
+    height: i64,
+    pending_outputs: &impl MapStore<i64, OutputChain>,
+) -> Result<Vec<(OutputId, Output)>> {
+    let mut outputs = Vec::new();
+
+    let mut key = height;
+    while let Some(oc) = pending_outputs.get(&key)? {
+        outputs.push(oc.output.clone());
+
+        if oc.next == 0 {
+            break;
+        }
+        key = oc.next;
+    }
+
+    // Chain is from latest to earliest.
+    outputs.reverse();
+
+    let txid = coinbase_txid(height);
+
+    let mut res = Vec::with_capacity(outputs.len());
+    for (n, output) in outputs.into_iter().enumerate() {
+        let output_id = OutputId {
+            txid,
+            n: n.try_into()?,
+        };
+        res.push((output_id, output));
+    }
+
+    Ok(res)
+}
+
+/// Remove outputs pending at height.
+pub fn release(height: i64, pending_outputs: &mut impl MapStore<i64, OutputChain>) -> Result<()> {
+    let mut key = height;
+    while let Some(oc) = pending_outputs.remove(&key)? {
+        if oc.next == 0 {
+            break;
+        }
+        key = oc.next;
+    }
+
+    Ok(())
+}
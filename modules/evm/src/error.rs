@@ -14,6 +14,7 @@ pub enum Error {
     AddOverflow,
     SubOverflow,
     InsufficientBalance,
+    UtxoError(fm_utxo::Error),
 }
 
 impl From<abcf::bs3::Error> for Error {
@@ -22,6 +23,12 @@ impl From<abcf::bs3::Error> for Error {
     }
 }
 
+impl From<fm_utxo::Error> for Error {
+    fn from(e: fm_utxo::Error) -> Self {
+        Self::UtxoError(e)
+    }
+}
+
 impl From<TryFromIntError> for Error {
     fn from(e: TryFromIntError) -> Self {
         Self::TryFromIntError(e)
@@ -70,6 +77,7 @@ impl From<Error> for abcf::Error {
             Error::InsufficientBalance => {
                 abcf::Error::ABCIApplicationError(80005, String::from("Sub overflow."))
             }
+            Error::UtxoError(e) => e.into(),
         }
     }
 }
@@ -7,6 +7,7 @@ use evm::{
     executor::stack::{StackState, StackSubstateMetadata},
     ExitError,
 };
+use fm_utxo::utils::OwnedOutputStore;
 use libfindora::{
     asset::XfrAmount,
     utxo::{Output, OutputId},
@@ -35,7 +36,7 @@ impl<
         'config,
         A: MapStore<H160, Account>,
         S: MapStore<H160, BTreeMap<H256, H256>>,
-        OO: MapStore<Address, Vec<OutputId>>,
+        OO: OwnedOutputStore,
         OS: MapStore<OutputId, Output>,
     > State<'config, A, S, OO, OS>
 {
@@ -50,21 +51,18 @@ impl<
     }
     fn basic_resulted(&self, address: H160) -> crate::Result<Basic> {
         let ua = Address::from(address);
-        let balance = if let Some(v) = self.latest_substate().owned_outputs.get(&ua)? {
-            let mut balance = 0;
-
-            for output_id in v.as_ref() {
-                if let Some(output) = self.latest_substate().outputs_set.get(output_id)? {
-                    if let XfrAmount::NonConfidential(e) = &output.amount {
-                        balance += e;
-                    }
+        let owned_outputs = &self.latest_substate().owned_outputs;
+        let count = owned_outputs.owned_count(&ua)?;
+
+        let mut balance = 0;
+
+        for output_id in owned_outputs.owned_outputs(&ua, 0, count)? {
+            if let Some(output) = self.latest_substate().outputs_set.get(&output_id)? {
+                if let XfrAmount::NonConfidential(e) = &output.amount {
+                    balance += e;
                 }
             }
-
-            balance
-        } else {
-            0
-        };
+        }
 
         let nonce = match self.latest_substate().accounts.get(&address)? {
             Some(e) => e.nonce,
@@ -82,7 +80,7 @@ impl<
         'config,
         A: MapStore<H160, Account>,
         S: MapStore<H160, BTreeMap<H256, H256>>,
-        OO: MapStore<Address, Vec<OutputId>>,
+        OO: OwnedOutputStore,
         OS: MapStore<OutputId, Output>,
     > Backend for State<'config, A, S, OO, OS>
 {
@@ -172,7 +170,7 @@ impl<
         'config,
         A: MapStore<H160, Account> + Clone,
         S: MapStore<H160, BTreeMap<H256, H256>> + Clone,
-        OO: MapStore<Address, Vec<OutputId>> + Clone,
+        OO: OwnedOutputStore + Clone,
         OS: MapStore<OutputId, Output> + Clone,
     > State<'config, A, S, OO, OS>
 {
@@ -244,15 +242,11 @@ impl<
     }
 
     fn _is_empty(&self, address: H160) -> crate::Result<bool> {
-        let r0 = if let Some(v) = self
+        let r0 = self
             .latest_substate()
             .owned_outputs
-            .get(&Address::from(address))?
-        {
-            v.len() == 0
-        } else {
-            true
-        };
+            .owned_count(&Address::from(address))?
+            == 0;
 
         let r1 = if let Some(account) = self.latest_substate().accounts.get(&address)? {
             account.code.is_empty() && account.nonce == 0
@@ -370,17 +364,16 @@ impl<
     }
 
     fn _reset_balance(&mut self, address: H160) -> crate::Result<()> {
-        let output_ids = if let Some(v) = self
-            .latest_substate_mut()
-            .owned_outputs
-            .get_mut(&Address::from(address))?
-        {
-            std::mem::take(v)
-        } else {
-            Vec::new()
-        };
+        let address = Address::from(address);
+        let owned_outputs = &mut self.latest_substate_mut().owned_outputs;
+
+        let count = owned_outputs.owned_count(&address)?;
+        let output_ids = owned_outputs.owned_outputs(&address, 0, count)?;
 
         for output_id in output_ids {
+            self.latest_substate_mut()
+                .owned_outputs
+                .remove_owned(&address, &output_id)?;
             self.latest_substate_mut().outputs_set.remove(&output_id)?;
         }
 
@@ -403,7 +396,7 @@ impl<
         'config,
         A: MapStore<H160, Account> + Clone,
         S: MapStore<H160, BTreeMap<H256, H256>> + Clone,
-        OO: MapStore<Address, Vec<OutputId>> + Clone,
+        OO: OwnedOutputStore + Clone,
         OS: MapStore<OutputId, Output> + Clone,
     > StackState<'config> for State<'config, A, S, OO, OS>
 {
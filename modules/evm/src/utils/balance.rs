@@ -1,6 +1,7 @@
 use std::collections::BTreeMap;
 
 use abcf::bs3::MapStore;
+use fm_utxo::utils::OwnedOutputStore;
 use libfindora::{
     asset::{Amount, AssetType, XfrAmount, XfrAssetType},
     utxo::{Output, OutputId},
@@ -13,21 +14,21 @@ use crate::{Error, Result};
 pub fn balance(
     address: Address,
     outputs_sets: &impl MapStore<OutputId, Output>,
-    owned_outputs: &impl MapStore<Address, Vec<OutputId>>,
+    owned_outputs: &impl OwnedOutputStore,
 ) -> Result<BTreeMap<AssetType, Amount>> {
     let mut amounts: BTreeMap<AssetType, Amount> = BTreeMap::new();
 
-    if let Some(ids) = owned_outputs.get(&address)? {
-        for id in ids.as_ref() {
-            if let Some(output) = outputs_sets.get(id)? {
-                if let (XfrAmount::NonConfidential(amount), XfrAssetType::NonConfidential(asset)) =
-                    (&output.amount, &output.asset)
-                {
-                    if let Some(a) = amounts.get_mut(asset) {
-                        *a = a.checked_add(*amount).ok_or(Error::AddOverflow)?;
-                    } else {
-                        amounts.insert(*asset, *amount);
-                    }
+    let count = owned_outputs.owned_count(&address)?;
+
+    for id in owned_outputs.owned_outputs(&address, 0, count)? {
+        if let Some(output) = outputs_sets.get(&id)? {
+            if let (XfrAmount::NonConfidential(amount), XfrAssetType::NonConfidential(asset)) =
+                (&output.amount, &output.asset)
+            {
+                if let Some(a) = amounts.get_mut(asset) {
+                    *a = a.checked_add(*amount).ok_or(Error::AddOverflow)?;
+                } else {
+                    amounts.insert(*asset, *amount);
                 }
             }
         }
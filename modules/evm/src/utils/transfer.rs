@@ -1,4 +1,5 @@
 use abcf::bs3::MapStore;
+use fm_utxo::utils::OwnedOutputStore;
 use libfindora::{
     asset::{Amount, AssetType, XfrAmount, XfrAssetType},
     utxo::{Output, OutputId},
@@ -14,25 +15,25 @@ pub fn transfer(
     asset: AssetType,
     oid: OutputId,
     outputs_sets: &mut impl MapStore<OutputId, Output>,
-    owned_outputs: &mut impl MapStore<Address, Vec<OutputId>>,
+    owned_outputs: &mut impl OwnedOutputStore,
 ) -> Result<()> {
     let mut target_amount = amount;
 
-    if let Some(ids) = owned_outputs.get_mut(&from)? {
-        while let Some(id) = ids.pop() {
-            if let Some(output) = outputs_sets.get_mut(&id)? {
-                if let (XfrAmount::NonConfidential(am), XfrAssetType::NonConfidential(at)) =
-                    (&mut output.amount, &output.asset)
-                {
-                    if at == &asset {
-                        if *am < target_amount {
-                            target_amount =
-                                target_amount.checked_sub(*am).ok_or(Error::SubOverflow)?;
-                            // remove output.
-                            outputs_sets.remove(&id)?;
-                        } else {
-                            *am = am.checked_sub(target_amount).ok_or(Error::SubOverflow)?;
-                        }
+    let count = owned_outputs.owned_count(&from)?;
+
+    for id in owned_outputs.owned_outputs(&from, 0, count)? {
+        if let Some(output) = outputs_sets.get_mut(&id)? {
+            if let (XfrAmount::NonConfidential(am), XfrAssetType::NonConfidential(at)) =
+                (&mut output.amount, &output.asset)
+            {
+                if at == &asset {
+                    if *am < target_amount {
+                        target_amount = target_amount.checked_sub(*am).ok_or(Error::SubOverflow)?;
+                        // remove output.
+                        outputs_sets.remove(&id)?;
+                        owned_outputs.remove_owned(&from, &id)?;
+                    } else {
+                        *am = am.checked_sub(target_amount).ok_or(Error::SubOverflow)?;
                     }
                 }
             }
@@ -48,16 +49,12 @@ pub fn transfer(
         amount: XfrAmount::NonConfidential(amount),
         asset: XfrAssetType::NonConfidential(asset),
         owner_memo: None,
+        lock: None,
     };
 
     outputs_sets.insert(oid.clone(), output)?;
 
-    if let Some(v) = owned_outputs.get_mut(&to)? {
-        v.push(oid);
-    } else {
-        let v = vec![oid];
-        owned_outputs.insert(to.clone(), v)?;
-    }
+    owned_outputs.insert_owned(&to, oid)?;
 
     Ok(())
 }
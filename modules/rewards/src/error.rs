@@ -0,0 +1,69 @@
+use std::num::TryFromIntError;
+
+use libfindora::{asset::Amount, staking::TendermintAddress, Address};
+
+#[derive(Debug)]
+pub enum Error {
+    Bs3Error(abcf::bs3::Error),
+    TryFromIntError(TryFromIntError),
+    CoinbaseError(fm_coinbase::Error),
+    StakingError(fm_staking::Error),
+    OverflowAdd,
+    MustSignedByDelegator(Address),
+    RewardsNotEnough(TendermintAddress, Address, Amount),
+}
+
+impl From<Error> for abcf::Error {
+    fn from(e: Error) -> abcf::Error {
+        match e {
+            Error::Bs3Error(e) => abcf::Error::ABCIApplicationError(80005, format!("{:?}", e)),
+            Error::TryFromIntError(e) => {
+                abcf::Error::ABCIApplicationError(80005, format!("{:?}", e))
+            }
+            Error::CoinbaseError(e) => e.into(),
+            Error::StakingError(e) => e.into(),
+            Error::OverflowAdd => {
+                abcf::Error::ABCIApplicationError(80007, String::from("add overflow"))
+            }
+            Error::MustSignedByDelegator(a) => abcf::Error::ABCIApplicationError(
+                100001,
+                format!("claim must be signed by delegator {:?}", a),
+            ),
+            Error::RewardsNotEnough(validator, delegator, amount) => {
+                abcf::Error::ABCIApplicationError(
+                    100002,
+                    format!(
+                        "rewards of {:?} from validator {:?} are {}, not enough",
+                        delegator, validator, amount
+                    ),
+                )
+            }
+        }
+    }
+}
+
+impl From<abcf::bs3::Error> for Error {
+    fn from(e: abcf::bs3::Error) -> Self {
+        Self::Bs3Error(e)
+    }
+}
+
+impl From<TryFromIntError> for Error {
+    fn from(e: TryFromIntError) -> Self {
+        Self::TryFromIntError(e)
+    }
+}
+
+impl From<fm_coinbase::Error> for Error {
+    fn from(e: fm_coinbase::Error) -> Self {
+        Self::CoinbaseError(e)
+    }
+}
+
+impl From<fm_staking::Error> for Error {
+    fn from(e: fm_staking::Error) -> Self {
+        Self::StakingError(e)
+    }
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
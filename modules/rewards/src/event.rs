@@ -0,0 +1,11 @@
+use libfindora::{asset::Amount, staking::TendermintAddress, Address};
+use serde::{Deserialize, Serialize};
+
+/// Rewards are claimed, paid out by coinbase at `height`.
+#[derive(Clone, Debug, Deserialize, Serialize, abcf::Event)]
+pub struct ClaimEvent {
+    pub validator: TendermintAddress,
+    pub delegator: Address,
+    pub amount: Amount,
+    pub height: i64,
+}
@@ -0,0 +1,145 @@
+use abcf::{
+    bs3::{
+        merkle::append_only::AppendOnlyMerkle,
+        model::{Map, Value},
+        MapStore, ValueStore,
+    },
+    module::types::{RequestCheckTx, RequestDeliverTx, ResponseCheckTx, ResponseDeliverTx},
+    Application, RPCContext, RPCResponse, TxnContext,
+};
+use fm_coinbase::CoinbaseModule;
+use fm_staking::StakingModule;
+use libfindora::{
+    asset::{Amount, XfrAmount, FRA},
+    staking::TendermintAddress,
+    utxo::Output,
+    Address,
+};
+
+use crate::{rpc, utils, ClaimEvent, Transaction, FRA_REWARDS};
+
+#[abcf::module(
+    name = "rewards",
+    version = 1,
+    impl_version = "0.1.1",
+    target_height = 0
+)]
+#[dependence(staking = "StakingModule", coinbase = "CoinbaseModule")]
+pub struct RewardsModule {
+    /// Rewards accrued by address from validator, not claimed yet.
+    #[stateful(merkle = "AppendOnlyMerkle")]
+    pub rewards: Map<(TendermintAddress, Address), Amount>,
+    /// Total FRA minted as rewards, bounded by `FRA_STAKING.mint_limit`.
+    #[stateful(merkle = "AppendOnlyMerkle")]
+    pub minted: Value<Amount>,
+    // Only a placeholder, will remove when abcf update.
+    #[stateless]
+    pub sl_value: Value<u32>,
+}
+
+#[abcf::rpcs]
+impl RewardsModule {
+    pub async fn rewards<'a>(
+        &mut self,
+        ctx: &mut RPCContext<'a, Self>,
+        params: rpc::RewardsRequest,
+    ) -> RPCResponse<rpc::RewardsResponse> {
+        let key = (params.validator, params.delegator);
+
+        let amount = match ctx.stateful.rewards.get(&key) {
+            Ok(v) => v.map(|v| *v).unwrap_or_default(),
+            Err(e) => return abcf::Error::from(crate::Error::from(e)).into(),
+        };
+
+        let minted = match ctx.stateful.minted.get() {
+            Ok(v) => v.map(|v| *v).unwrap_or_default(),
+            Err(e) => return abcf::Error::from(crate::Error::from(e)).into(),
+        };
+
+        RPCResponse::new(rpc::RewardsResponse { amount, minted })
+    }
+}
+
+/// Module's block logic.
+#[abcf::application]
+impl Application for RewardsModule {
+    type Transaction = Transaction;
+
+    async fn begin_block(
+        &mut self,
+        context: &mut abcf::AppContext<'_, Self>,
+        req: &abcf::tm_protos::abci::RequestBeginBlock,
+    ) {
+        let votes = utils::votes(req);
+
+        let staking = &context.deps.staking.stateful;
+
+        if let Err(e) = utils::distribute(
+            &votes,
+            &staking.global_power,
+            &staking.delegators,
+            &staking.validator_staker,
+            &staking.validator_profile,
+            &mut context.stateful.minted,
+            &mut context.stateful.rewards,
+        ) {
+            log::error!("distribute rewards failed: {:?}", e);
+        }
+    }
+
+    async fn check_tx(
+        &mut self,
+        context: &mut TxnContext<'_, Self>,
+        req: &RequestCheckTx<Self::Transaction>,
+    ) -> abcf::Result<ResponseCheckTx> {
+        let tx = &req.tx;
+
+        utils::check_claim(&tx.signers, &mut context.stateful.rewards, &tx.claims)?;
+
+        Ok(Default::default())
+    }
+
+    /// Execute transaction on state.
+    async fn deliver_tx(
+        &mut self,
+        context: &mut TxnContext<'_, Self>,
+        req: &RequestDeliverTx<Self::Transaction>,
+    ) -> abcf::Result<ResponseDeliverTx> {
+        let tx = &req.tx;
+
+        utils::check_claim(&tx.signers, &mut context.stateful.rewards, &tx.claims)?;
+
+        let coinbase = &mut context.deps.coinbase;
+        let height = coinbase.module.block_height + FRA_REWARDS.claim_block;
+
+        for claim in &tx.claims {
+            let output = Output {
+                address: claim.delegator.clone(),
+                amount: XfrAmount::NonConfidential(claim.amount),
+                asset: FRA.asset_type,
+                owner_memo: None,
+                lock: None,
+            };
+
+            fm_coinbase::utils::mint(
+                height,
+                output,
+                &mut coinbase.stateful.pending_outputs,
+                &mut coinbase.stateful.pending_slot,
+            )?;
+
+            context.events.emit(&ClaimEvent {
+                validator: utils::claim_validator(claim).clone(),
+                delegator: claim.delegator.clone(),
+                amount: claim.amount,
+                height,
+            })?;
+        }
+
+        Ok(Default::default())
+    }
+}
+
+/// Module's methods.
+#[abcf::methods]
+impl RewardsModule {}
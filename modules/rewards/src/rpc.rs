@@ -0,0 +1,16 @@
+use libfindora::{asset::Amount, staking::TendermintAddress, Address};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RewardsRequest {
+    pub validator: TendermintAddress,
+    pub delegator: Address,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RewardsResponse {
+    /// Rewards accrued but not claimed.
+    pub amount: Amount,
+    /// Total FRA minted as rewards.
+    pub minted: Amount,
+}
@@ -0,0 +1,28 @@
+use std::collections::BTreeSet;
+
+use libfindora::{rewards::RewardInfo, Address};
+
+#[derive(Debug, Default)]
+pub struct Transaction {
+    pub claims: Vec<RewardInfo>,
+    pub signers: BTreeSet<Address>,
+}
+
+impl TryFrom<&libfindora::Transaction> for Transaction {
+    type Error = abcf::Error;
+
+    fn try_from(t: &libfindora::Transaction) -> Result<Self, Self::Error> {
+        let tx = libfindora::rewards::Transaction::try_from(t)?;
+
+        let signers = if tx.infos.is_empty() {
+            BTreeSet::new()
+        } else {
+            t.verify()?
+        };
+
+        Ok(Transaction {
+            claims: tx.infos,
+            signers,
+        })
+    }
+}
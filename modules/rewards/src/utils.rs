@@ -0,0 +1,339 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use abcf::{
+    bs3::{MapStore, ValueStore},
+    tm_protos::abci::RequestBeginBlock,
+};
+use fm_staking::{Power, FRA_STAKING};
+use libfindora::{
+    asset::Amount,
+    rewards::{Operation, RewardInfo},
+    staking::{TendermintAddress, ValidatorProfile, COMMISSION_RATE_DENOMINATOR},
+    Address,
+};
+
+use crate::{Error, Result, FRA_REWARDS};
+
+/// Validator signed last block.
+#[derive(Debug, Clone)]
+pub struct Vote {
+    pub validator: TendermintAddress,
+    pub power: Power,
+}
+
+pub fn votes(req: &RequestBeginBlock) -> Vec<Vote> {
+    let mut votes = Vec::new();
+
+    if let Some(lci) = &req.last_commit_info {
+        for vote in &lci.votes {
+            if !vote.signed_last_block {
+                continue;
+            }
+
+            if let Some(validator) = &vote.validator {
+                if validator.power > 0 && validator.address.len() == 20 {
+                    votes.push(Vote {
+                        validator: TendermintAddress::from(&validator.address),
+                        power: validator.power as Power,
+                    });
+                }
+            }
+        }
+    }
+
+    votes
+}
+
+fn mul_div(amount: u64, mul: u64, div: u64) -> u64 {
+    if div == 0 {
+        return 0;
+    }
+    ((amount as u128) * (mul as u128) / (div as u128)) as u64
+}
+
+/// Inflation of one block, total minted never exceeds mint limit.
+pub fn block_inflation(global_power: Power, minted: Amount) -> Amount {
+    let rate = FRA_REWARDS.annual_rate;
+    let per_year = mul_div(global_power, rate[0], rate[1]);
+    let per_block = per_year / FRA_REWARDS.blocks_per_year;
+
+    per_block.min(FRA_STAKING.mint_limit.saturating_sub(minted))
+}
+
+fn accrue(
+    rewards: &mut impl MapStore<(TendermintAddress, Address), Amount>,
+    validator: &TendermintAddress,
+    address: &Address,
+    amount: Amount,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let key = (validator.clone(), address.clone());
+    let current = rewards.get(&key)?.map(|v| *v).unwrap_or_default();
+    let total = current.checked_add(amount).ok_or(Error::OverflowAdd)?;
+    rewards.insert(key, total)?;
+
+    Ok(())
+}
+
+/// Distribute inflation of block to validators signed it.
+///
+/// Share of validator is in proportion to its power, staker keeps commission
+/// of validator profile and the rest is split by delegation amount. Return amount minted.
+pub fn distribute(
+    votes: &[Vote],
+    global_power: &impl ValueStore<Power>,
+    delegators: &impl MapStore<TendermintAddress, BTreeMap<Address, Amount>>,
+    validator_staker: &impl MapStore<TendermintAddress, Address>,
+    validator_profile: &impl MapStore<TendermintAddress, ValidatorProfile>,
+    minted: &mut impl ValueStore<Amount>,
+    rewards: &mut impl MapStore<(TendermintAddress, Address), Amount>,
+) -> Result<Amount> {
+    let global_power = global_power.get()?.map(|v| *v).unwrap_or_default();
+    let already = minted.get()?.map(|v| *v).unwrap_or_default();
+
+    let inflation = block_inflation(global_power, already);
+    let total_power: u64 = votes.iter().map(|v| v.power).sum();
+
+    let mut distributed: Amount = 0;
+
+    for vote in votes {
+        let staker = match validator_staker.get(&vote.validator)? {
+            Some(s) => s.clone(),
+            None => continue,
+        };
+
+        let share = mul_div(inflation, vote.power, total_power);
+        let rate = fm_staking::utils::commission_rate(&vote.validator, validator_profile)?;
+        let commission = mul_div(share, rate, COMMISSION_RATE_DENOMINATOR);
+        let rest = share - commission;
+
+        let mut paid = 0;
+
+        if let Some(map) = delegators.get(&vote.validator)? {
+            let validator_power: u64 = map.values().sum();
+
+            for (delegator, amount) in map.iter() {
+                let reward = mul_div(rest, *amount, validator_power);
+                accrue(rewards, &vote.validator, delegator, reward)?;
+                paid += reward;
+            }
+        }
+
+        // Remainder of rounding goes to staker.
+        accrue(rewards, &vote.validator, &staker, share - paid)?;
+
+        distributed += share;
+    }
+
+    minted.set(already.checked_add(distributed).ok_or(Error::OverflowAdd)?)?;
+
+    Ok(distributed)
+}
+
+/// Validator of claim.
+pub fn claim_validator(info: &RewardInfo) -> &TendermintAddress {
+    match &info.operation {
+        Operation::Claim(c) => &c.validator,
+    }
+}
+
+pub fn check_claim(
+    signers: &BTreeSet<Address>,
+    rewards: &mut impl MapStore<(TendermintAddress, Address), Amount>,
+    claims: &[RewardInfo],
+) -> Result<()> {
+    for claim in claims {
+        if !signers.contains(&claim.delegator) {
+            return Err(Error::MustSignedByDelegator(claim.delegator.clone()));
+        }
+
+        let validator = claim_validator(claim);
+        let key = (validator.clone(), claim.delegator.clone());
+        let current = rewards.get(&key)?.map(|v| *v).unwrap_or_default();
+
+        let rest = current.checked_sub(claim.amount).ok_or_else(|| {
+            Error::RewardsNotEnough(validator.clone(), claim.delegator.clone(), current)
+        })?;
+
+        if rest == 0 {
+            rewards.remove(&key)?;
+        } else {
+            rewards.insert(key, rest)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use abcf::bs3::{
+        backend::MemoryBackend,
+        model::{Map, Value},
+        SnapshotableStorage,
+    };
+    use libfindora::rewards::Claim;
+
+    type Store<T> = SnapshotableStorage<MemoryBackend, T>;
+
+    macro_rules! store {
+        () => {
+            SnapshotableStorage::new(Default::default(), MemoryBackend::new()).unwrap()
+        };
+    }
+
+    /// Global power of 1000 inflation per block.
+    const GLOBAL_POWER: Power = 26_280_000_000;
+
+    fn validator(n: u8) -> TendermintAddress {
+        TendermintAddress([n; 20])
+    }
+
+    fn address(n: u8) -> Address {
+        Address::from(&[n; 20][..])
+    }
+
+    fn claim(delegator: Address, validator: TendermintAddress, amount: Amount) -> RewardInfo {
+        RewardInfo {
+            delegator,
+            amount,
+            operation: Operation::Claim(Claim { validator }),
+        }
+    }
+
+    #[test]
+    fn test_block_inflation() {
+        assert_eq!(block_inflation(GLOBAL_POWER, 0), 1000);
+        assert_eq!(
+            block_inflation(GLOBAL_POWER, FRA_STAKING.mint_limit - 10),
+            10
+        );
+        assert_eq!(block_inflation(GLOBAL_POWER, FRA_STAKING.mint_limit), 0);
+    }
+
+    #[test]
+    fn test_distribute() {
+        let mut global_power: Store<Value<Power>> = store!();
+        let mut delegators: Store<Map<TendermintAddress, BTreeMap<Address, Amount>>> = store!();
+        let mut validator_staker: Store<Map<TendermintAddress, Address>> = store!();
+        let mut validator_profile: Store<Map<TendermintAddress, ValidatorProfile>> = store!();
+        let mut minted: Store<Value<Amount>> = store!();
+        let mut rewards: Store<Map<(TendermintAddress, Address), Amount>> = store!();
+
+        global_power.set(GLOBAL_POWER).unwrap();
+
+        // Commission of 10%.
+        delegators
+            .insert(
+                validator(1),
+                BTreeMap::from([(address(1), 2), (address(3), 1)]),
+            )
+            .unwrap();
+        validator_staker.insert(validator(1), address(1)).unwrap();
+        validator_profile
+            .insert(
+                validator(1),
+                ValidatorProfile {
+                    commission_rate: 100_000,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+        // Default commission of 5%.
+        delegators
+            .insert(
+                validator(2),
+                BTreeMap::from([(address(2), 1), (address(3), 2)]),
+            )
+            .unwrap();
+        validator_staker.insert(validator(2), address(2)).unwrap();
+
+        let votes = vec![
+            Vote {
+                validator: validator(1),
+                power: 3,
+            },
+            Vote {
+                validator: validator(2),
+                power: 1,
+            },
+        ];
+
+        let distributed = distribute(
+            &votes,
+            &global_power,
+            &delegators,
+            &validator_staker,
+            &validator_profile,
+            &mut minted,
+            &mut rewards,
+        )
+        .unwrap();
+
+        assert_eq!(distributed, 1000);
+        assert_eq!(minted.get().unwrap().map(|v| *v), Some(1000));
+
+        let reward = |v: u8, a: u8| {
+            rewards
+                .get(&(validator(v), address(a)))
+                .unwrap()
+                .map(|r| *r)
+        };
+
+        // Share 750, commission 75, rest 675 split 2:1.
+        assert_eq!(reward(1, 1), Some(75 + 450));
+        assert_eq!(reward(1, 3), Some(225));
+        // Share 250, commission 12, rest 238 split 1:2, remainder 1 goes to staker.
+        assert_eq!(reward(2, 2), Some(12 + 79 + 1));
+        assert_eq!(reward(2, 3), Some(158));
+    }
+
+    #[test]
+    fn test_check_claim() {
+        let mut rewards: Store<Map<(TendermintAddress, Address), Amount>> = store!();
+        rewards.insert((validator(1), address(1)), 100).unwrap();
+
+        let signers = BTreeSet::from([address(1)]);
+
+        let res = check_claim(
+            &BTreeSet::new(),
+            &mut rewards,
+            &[claim(address(1), validator(1), 10)],
+        );
+        assert!(matches!(res, Err(Error::MustSignedByDelegator(_))));
+
+        let res = check_claim(
+            &signers,
+            &mut rewards,
+            &[claim(address(1), validator(1), 101)],
+        );
+        assert!(matches!(res, Err(Error::RewardsNotEnough(_, _, 100))));
+
+        check_claim(
+            &signers,
+            &mut rewards,
+            &[claim(address(1), validator(1), 40)],
+        )
+        .unwrap();
+        assert_eq!(
+            rewards
+                .get(&(validator(1), address(1)))
+                .unwrap()
+                .map(|v| *v),
+            Some(60)
+        );
+
+        check_claim(
+            &signers,
+            &mut rewards,
+            &[claim(address(1), validator(1), 60)],
+        )
+        .unwrap();
+        assert!(rewards.get(&(validator(1), address(1))).unwrap().is_none());
+    }
+}
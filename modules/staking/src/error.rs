@@ -1,6 +1,6 @@
 use std::num::TryFromIntError;
 
-use libfindora::asset::Amount;
+use libfindora::{asset::Amount, Address};
 
 #[derive(Debug)]
 pub enum Error {
@@ -17,6 +17,17 @@ pub enum Error {
     DelegateAmountNotEnough,
     NoTendermintAddress,
     IsOptionNone,
+    InvalidProfile(String),
+    ProfileMustSelfDelegate,
+    CommissionChangeTooLarge(u64, u64),
+    MustSignedByStaker(Address),
+    MustSignedByDelegator(Address),
+    UnbondingNotFound(u64),
+    UnbondingNotPending(u64),
+    CoinbaseError(fm_coinbase::Error),
+    RedelegateSameValidator,
+    NotJailed,
+    JailCooldown(i64),
 }
 
 impl From<Error> for abcf::Error {
@@ -60,6 +71,46 @@ impl From<Error> for abcf::Error {
                 abcf::Error::ABCIApplicationError(90002, "No tendermint address.".to_string())
             }
             Error::IsOptionNone => abcf::Error::ABCIApplicationError(90002, "Is none.".to_string()),
+            Error::InvalidProfile(e) => {
+                abcf::Error::ABCIApplicationError(90002, format!("Invalid profile: {}", e))
+            }
+            Error::ProfileMustSelfDelegate => abcf::Error::ABCIApplicationError(
+                90002,
+                "Profile must be set by self delegate.".to_string(),
+            ),
+            Error::CommissionChangeTooLarge(base, max) => abcf::Error::ABCIApplicationError(
+                90002,
+                format!(
+                    "Commission rate change too large in this epoch, [base: {}, max change: {}]",
+                    base, max
+                ),
+            ),
+            Error::MustSignedByStaker(a) => {
+                abcf::Error::ABCIApplicationError(90002, format!("Must signed by staker {:?}.", a))
+            }
+            Error::MustSignedByDelegator(a) => abcf::Error::ABCIApplicationError(
+                90002,
+                format!("Must signed by delegator {:?}.", a),
+            ),
+            Error::UnbondingNotFound(id) => {
+                abcf::Error::ABCIApplicationError(90002, format!("Unbonding {} not found.", id))
+            }
+            Error::UnbondingNotPending(id) => abcf::Error::ABCIApplicationError(
+                90002,
+                format!("Unbonding {} is not pending.", id),
+            ),
+            Error::CoinbaseError(e) => e.into(),
+            Error::RedelegateSameValidator => abcf::Error::ABCIApplicationError(
+                90002,
+                "Redelegate to same validator.".to_string(),
+            ),
+            Error::NotJailed => {
+                abcf::Error::ABCIApplicationError(90002, "Validator is not jailed.".to_string())
+            }
+            Error::JailCooldown(height) => abcf::Error::ABCIApplicationError(
+                90002,
+                format!("Validator can unjail after height {}.", height),
+            ),
         }
     }
 }
@@ -76,4 +127,10 @@ impl From<TryFromIntError> for Error {
     }
 }
 
+impl From<fm_coinbase::Error> for Error {
+    fn from(e: fm_coinbase::Error) -> Self {
+        Error::CoinbaseError(e)
+    }
+}
+
 pub type Result<T> = core::result::Result<T, Error>;
@@ -17,6 +17,8 @@ pub use error::{Error, Result};
 
 pub mod utils;
 
+pub mod rpc;
+
 pub type Power = u64;
 
 pub struct FraStaking {
@@ -26,6 +28,16 @@ pub struct FraStaking {
     pub min_delegate: Amount,
     pub max_percent_per_validator: [u32; 2],
     pub undelegate_block: i64,
+    /// Blocks of an epoch for commission rate changes.
+    pub epoch_block: i64,
+    /// Max change of commission rate in an epoch, in millionths.
+    pub max_commission_change: u64,
+    /// Commission rate of validator without profile, in millionths.
+    pub default_commission_rate: u64,
+    /// Validator missed these blocks in a row is jailed.
+    pub jail_missed_block: u64,
+    /// Blocks before jailed validator can unjail.
+    pub jail_block: i64,
 }
 
 impl FraStaking {
@@ -45,4 +57,9 @@ pub const FRA_STAKING: FraStaking = FraStaking {
     min_delegate: 1,
     max_percent_per_validator: [1, 5],
     undelegate_block: 5,
+    epoch_block: 24 * 60 * 60 / 15,
+    max_commission_change: 10_000,
+    default_commission_rate: 50_000,
+    jail_missed_block: 720,
+    jail_block: 24 * 60 * 60 / 15,
 };
@@ -1,4 +1,4 @@
-use crate::{transaction::Operation, utils, Power, Result, Transaction, FRA_STAKING};
+use crate::{rpc, transaction::Operation, utils, Error, Power, Result, Transaction, FRA_STAKING};
 use abcf::{
     bs3::{
         merkle::append_only::AppendOnlyMerkle,
@@ -11,16 +11,20 @@ use abcf::{
         ResponseEndBlock,
     },
     tm_protos::abci::ValidatorUpdate,
-    Application, {AppContext, TxnContext},
+    Application, RPCContext, RPCResponse, {AppContext, TxnContext},
 };
 use fm_coinbase::CoinbaseModule;
 use libfindora::{
     asset::{Amount, XfrAmount, FRA},
-    staking::{TendermintAddress, ValidatorPublicKey},
+    staking::{Delegate, TendermintAddress, UnbondingEntry, ValidatorProfile, ValidatorPublicKey},
     utxo::Output,
     Address,
 };
-use std::{collections::BTreeMap, mem, ops::Deref};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    mem,
+    ops::Deref,
+};
 
 #[abcf::module(
     name = "staking",
@@ -57,10 +61,94 @@ pub struct StakingModule {
     /// Validator power.
     #[stateful(merkle = "AppendOnlyMerkle")]
     pub powers: Map<TendermintAddress, Power>,
+
+    /// Name, website, description and commission rate of validator.
+    #[stateful(merkle = "AppendOnlyMerkle")]
+    pub validator_profile: Map<TendermintAddress, ValidatorProfile>,
+
+    /// Epoch of last commission rate change, with rate at start of epoch.
+    #[stateful(merkle = "AppendOnlyMerkle")]
+    pub commission_epoch: Map<TendermintAddress, (i64, u64)>,
+
+    /// Unbonding entries by id.
+    #[stateful(merkle = "AppendOnlyMerkle")]
+    pub unbonding: Map<u64, UnbondingEntry>,
+
+    /// Id of next unbonding entry.
+    #[stateful(merkle = "AppendOnlyMerkle")]
+    pub unbonding_count: Value<u64>,
+
+    /// Ids of pending unbonding entries by completion height.
+    #[stateful(merkle = "AppendOnlyMerkle")]
+    pub unbonding_queue: Map<i64, Vec<u64>>,
+
+    /// Amount redelegated away from validator, slashable in unbonding window.
+    #[stateful(merkle = "AppendOnlyMerkle")]
+    pub redelegations: Map<TendermintAddress, Vec<utils::Redelegation>>,
+
+    /// Jailed validator with height it can unjail.
+    #[stateful(merkle = "AppendOnlyMerkle")]
+    pub jailed: Map<TendermintAddress, i64>,
+
+    /// Blocks missed in a row by validator.
+    #[stateful(merkle = "AppendOnlyMerkle")]
+    pub missed_blocks: Map<TendermintAddress, u64>,
+
+    /// Ids of unbonding entries by delegator.
+    #[stateless]
+    pub delegator_unbonding: Map<Address, BTreeSet<u64>>,
 }
 
 #[abcf::rpcs]
-impl StakingModule {}
+impl StakingModule {
+    pub async fn unbonding<'a>(
+        &mut self,
+        ctx: &mut RPCContext<'a, Self>,
+        params: rpc::UnbondingRequest,
+    ) -> RPCResponse<UnbondingEntry> {
+        match utils::get_unbonding(params.id, &ctx.stateful.unbonding) {
+            Ok(r) => RPCResponse::new(r),
+            Err(e) => abcf::Error::from(e).into(),
+        }
+    }
+
+    /// Jailed status of validator.
+    pub async fn jailed<'a>(
+        &mut self,
+        ctx: &mut RPCContext<'a, Self>,
+        params: rpc::JailedRequest,
+    ) -> RPCResponse<rpc::JailedResponse> {
+        match ctx.stateful.jailed.get(&params.validator) {
+            Ok(v) => {
+                let unjail_height = v.map(|h| *h);
+                RPCResponse::new(rpc::JailedResponse {
+                    validator: params.validator,
+                    jailed: unjail_height.is_some(),
+                    unjail_height,
+                })
+            }
+            Err(e) => abcf::Error::from(crate::Error::from(e)).into(),
+        }
+    }
+
+    /// Unbonding entries of delegator.
+    pub async fn delegator_unbonding<'a>(
+        &mut self,
+        ctx: &mut RPCContext<'a, Self>,
+        params: rpc::DelegatorUnbondingRequest,
+    ) -> RPCResponse<rpc::UnbondingResponse> {
+        let res = utils::get_delegator_unbonding(
+            &params.delegator,
+            &ctx.stateful.unbonding,
+            &ctx.stateless.delegator_unbonding,
+        );
+
+        match res {
+            Ok(entries) => RPCResponse::new(rpc::UnbondingResponse { entries }),
+            Err(e) => abcf::Error::from(e).into(),
+        }
+    }
+}
 
 /// Module's block logic.
 #[abcf::application]
@@ -80,9 +168,12 @@ impl Application for StakingModule {
     }
 
     async fn begin_block(&mut self, context: &mut AppContext<'_, Self>, req: &RequestBeginBlock) {
+        let height = req.header.as_ref().map(|h| h.height).unwrap_or_default();
+
         let evidences = utils::BlockEvidence::from(req);
 
         let mut updates = utils::penalty(
+            height,
             &evidences,
             &mut context.stateful.powers,
             &mut context.stateful.global_power,
@@ -90,10 +181,33 @@ impl Application for StakingModule {
             &mut context.stateful.delegators,
             &mut context.stateful.validator_staker,
             &mut context.stateful.validator_pubkey,
+            &mut context.stateful.redelegations,
+            &context.stateful.jailed,
         )
         .unwrap_or_default();
 
         self.vote_updaters.append(&mut updates);
+
+        let res = utils::check_jail(req, &evidences, &mut context.stateful.missed_blocks).and_then(
+            |validators| {
+                utils::apply_jail(
+                    height,
+                    &validators,
+                    &mut context.stateful.jailed,
+                    &context.stateful.validator_pubkey,
+                )
+            },
+        );
+
+        match res {
+            Ok(mut updates) => self.vote_updaters.append(&mut updates),
+            Err(e) => log::error!("jail validators failed: {:?}", e),
+        }
+
+        // Unbonding entries are only completed at their height, can't retry later.
+        if let Err(e) = self.release_unbonding(context, height) {
+            panic!("release unbonding failed: {:?}, Please restart node.", e);
+        }
     }
 
     async fn deliver_tx(
@@ -107,22 +221,6 @@ impl Application for StakingModule {
 
         self.vote_updaters.append(&mut res);
 
-        for info in &tx.infos {
-            if let Operation::Undelegate(_) = info.operation {
-                let output = Output {
-                    address: info.delegator.clone(),
-                    amount: XfrAmount::NonConfidential(info.amount),
-                    asset: FRA.asset_type,
-                    owner_memo: None,
-                };
-                fm_coinbase::utils::mint(
-                    context.deps.coinbase.module.block_height + FRA_STAKING.undelegate_block,
-                    output,
-                    &mut context.deps.coinbase.stateful.pending_outputs,
-                )?;
-            }
-        }
-
         Ok(Default::default())
     }
 
@@ -168,6 +266,8 @@ impl StakingModule {
                         &mut context.stateful.validator_pubkey,
                     )?;
 
+                    utils::apply_profile(op, &mut context.stateful.validator_profile)?;
+
                     let power = utils::apply_global(
                         info.amount,
                         op,
@@ -175,7 +275,7 @@ impl StakingModule {
                         &mut context.stateful.powers,
                     )?;
 
-                    let td_power: i64 = power.try_into()?;
+                    let td_power = utils::vote_power(&op.address, power, &context.stateful.jailed)?;
 
                     res.insert(validator_pubkey, td_power);
 
@@ -202,14 +302,152 @@ impl StakingModule {
                         if let Some(validator_pubkey) =
                             context.stateful.validator_pubkey.get(&addr)?
                         {
-                            let td_power: i64 = power.try_into()?;
+                            let td_power =
+                                utils::vote_power(&addr, power, &context.stateful.jailed)?;
+                            res.insert(validator_pubkey.deref().clone(), td_power);
+                        }
+                    }
+
+                    utils::apply_unbonding(
+                        &info.delegator,
+                        &op.address,
+                        info.amount,
+                        context.deps.coinbase.module.block_height + FRA_STAKING.undelegate_block,
+                        &mut context.stateful.unbonding,
+                        &mut context.stateful.unbonding_count,
+                        &mut context.stateful.unbonding_queue,
+                        &mut context.stateless.delegator_unbonding,
+                    )?;
+                }
+                Operation::CancelUnbonding(op) => {
+                    let entry = utils::cancel_unbonding(
+                        context.deps.coinbase.module.block_height,
+                        &tx.signers,
+                        op,
+                        &mut context.stateful.unbonding,
+                        &mut context.stateful.unbonding_queue,
+                    )?;
+
+                    // Delegate amount back to validator.
+                    let op = Delegate {
+                        address: entry.validator.clone(),
+                        validator: None,
+                        memo: None,
+                        profile: None,
+                    };
+
+                    let power = utils::apply_global(
+                        entry.amount,
+                        &op,
+                        &mut context.stateful.global_power,
+                        &mut context.stateful.powers,
+                    )?;
+
+                    if let Some(validator_pubkey) =
+                        context.stateful.validator_pubkey.get(&op.address)?
+                    {
+                        let td_power =
+                            utils::vote_power(&op.address, power, &context.stateful.jailed)?;
+                        res.insert(validator_pubkey.deref().clone(), td_power);
+                    }
+
+                    utils::apply_detail(
+                        &entry.delegator,
+                        entry.amount,
+                        &op,
+                        &mut context.stateful.delegators,
+                        &mut context.stateless.delegation_amount,
+                    )?;
+                }
+                Operation::Redelegate(op) => {
+                    if !tx.signers.contains(&info.delegator) {
+                        return Err(Error::MustSignedByDelegator(info.delegator.clone()));
+                    }
+
+                    let addr_power_vec = utils::apply_redelegate(
+                        context.deps.coinbase.module.block_height,
+                        &info.delegator,
+                        info.amount,
+                        op,
+                        &mut context.stateful.delegators,
+                        &mut context.stateful.global_power,
+                        &mut context.stateful.powers,
+                        &mut context.stateless.delegation_amount,
+                        &context.stateful.validator_pubkey,
+                        &mut context.stateful.redelegations,
+                    )?;
+
+                    for (addr, power) in addr_power_vec.into_iter() {
+                        if let Some(validator_pubkey) =
+                            context.stateful.validator_pubkey.get(&addr)?
+                        {
+                            let td_power =
+                                utils::vote_power(&addr, power, &context.stateful.jailed)?;
                             res.insert(validator_pubkey.deref().clone(), td_power);
                         }
                     }
                 }
+                Operation::Unjail(op) => {
+                    let (validator_pubkey, td_power) = utils::apply_unjail(
+                        context.deps.coinbase.module.block_height,
+                        &tx.signers,
+                        op,
+                        &context.stateful.validator_staker,
+                        &context.stateful.validator_pubkey,
+                        &context.stateful.powers,
+                        &mut context.stateful.jailed,
+                        &mut context.stateful.missed_blocks,
+                    )?;
+
+                    res.insert(validator_pubkey, td_power);
+                }
+                Operation::UpdateValidator(op) => {
+                    utils::apply_update_validator(
+                        context.deps.coinbase.module.block_height,
+                        &tx.signers,
+                        op,
+                        &context.stateful.validator_staker,
+                        &mut context.stateful.validator_profile,
+                        &mut context.stateful.commission_epoch,
+                    )?;
+                }
             }
         }
 
         Ok(res)
     }
+
+    /// Pay out unbonding entries completed at height, released by coinbase in next block.
+    pub fn release_unbonding(
+        &mut self,
+        context: &mut AppContext<'_, Self>,
+        height: i64,
+    ) -> Result<()> {
+        let coinbase = &mut context.deps.coinbase.stateful;
+
+        utils::complete_unbonding(
+            height,
+            &mut context.stateful.unbonding,
+            &mut context.stateful.unbonding_queue,
+            |entry| {
+                let output = Output {
+                    address: entry.delegator.clone(),
+                    amount: XfrAmount::NonConfidential(entry.amount),
+                    asset: FRA.asset_type,
+                    owner_memo: None,
+                    lock: None,
+                };
+
+                fm_coinbase::utils::mint(
+                    height + 1,
+                    output,
+                    &mut coinbase.pending_outputs,
+                    &mut coinbase.pending_slot,
+                )
+                .map_err(Error::CoinbaseError)
+            },
+        )?;
+
+        Ok(())
+    }
 }
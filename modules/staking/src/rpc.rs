@@ -0,0 +1,34 @@
+use libfindora::{
+    staking::{TendermintAddress, UnbondingEntry},
+    Address,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UnbondingRequest {
+    pub id: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DelegatorUnbondingRequest {
+    pub delegator: Address,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct UnbondingResponse {
+    /// Entries of delegator with id, include completed and cancelled.
+    pub entries: Vec<(u64, UnbondingEntry)>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct JailedRequest {
+    pub validator: TendermintAddress,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct JailedResponse {
+    pub validator: TendermintAddress,
+    pub jailed: bool,
+    /// Height validator can unjail, none if not jailed.
+    pub unjail_height: Option<i64>,
+}
@@ -1,9 +1,9 @@
 use libfindora::{
     asset::{Amount, FRA},
-    staking::{Delegate, Undelegate},
+    staking::{CancelUnbonding, Delegate, Redelegate, Undelegate, Unjail, UpdateValidator},
     Address,
 };
-use std::convert::TryFrom;
+use std::{collections::BTreeSet, convert::TryFrom};
 
 use crate::Error;
 
@@ -11,6 +11,10 @@ use crate::Error;
 pub enum Operation {
     Delegate(Delegate),
     Undelegate(Undelegate),
+    UpdateValidator(UpdateValidator),
+    CancelUnbonding(CancelUnbonding),
+    Redelegate(Redelegate),
+    Unjail(Unjail),
 }
 
 #[derive(Debug, Clone)]
@@ -23,6 +27,8 @@ pub struct StakingInfo {
 #[derive(Debug, Clone, Default)]
 pub struct Transaction {
     pub infos: Vec<StakingInfo>,
+    /// Signers of transaction, only verified for operations need owner's signature.
+    pub signers: BTreeSet<Address>,
 }
 
 impl TryFrom<&libfindora::Transaction> for Transaction {
@@ -36,7 +42,11 @@ impl TryFrom<&libfindora::Transaction> for Transaction {
         for output in tx.outputs.iter() {
             match &output.operation {
                 libfindora::OutputOperation::Delegate(_)
-                | libfindora::OutputOperation::Undelegate(_) => outputs.push(output.clone()),
+                | libfindora::OutputOperation::Undelegate(_)
+                | libfindora::OutputOperation::UpdateValidator(_)
+                | libfindora::OutputOperation::CancelUnbonding(_)
+                | libfindora::OutputOperation::Redelegate(_)
+                | libfindora::OutputOperation::Unjail(_) => outputs.push(output.clone()),
                 _ => continue,
             }
         }
@@ -60,6 +70,16 @@ impl TryFrom<&libfindora::Transaction> for Transaction {
                     libfindora::OutputOperation::Undelegate(op) => {
                         Operation::Undelegate(op.clone())
                     }
+                    libfindora::OutputOperation::UpdateValidator(op) => {
+                        Operation::UpdateValidator(op.clone())
+                    }
+                    libfindora::OutputOperation::CancelUnbonding(op) => {
+                        Operation::CancelUnbonding(op.clone())
+                    }
+                    libfindora::OutputOperation::Redelegate(op) => {
+                        Operation::Redelegate(op.clone())
+                    }
+                    libfindora::OutputOperation::Unjail(op) => Operation::Unjail(op.clone()),
                     _ => {
                         return Err(abcf::Error::ABCIApplicationError(
                             90009,
@@ -76,6 +96,20 @@ impl TryFrom<&libfindora::Transaction> for Transaction {
             }
         }
 
-        Ok(Transaction { infos })
+        let signers = if infos.iter().any(|i| {
+            matches!(
+                i.operation,
+                Operation::UpdateValidator(_)
+                    | Operation::CancelUnbonding(_)
+                    | Operation::Redelegate(_)
+                    | Operation::Unjail(_)
+            )
+        }) {
+            tx.verify()?
+        } else {
+            BTreeSet::new()
+        };
+
+        Ok(Transaction { infos, signers })
     }
 }
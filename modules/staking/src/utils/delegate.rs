@@ -29,7 +29,10 @@ pub fn apply_delegated(
             .clone()
     };
 
-    validator_staker.insert(op.address.clone(), delegator.clone())?;
+    if op.validator.is_some() {
+        validator_staker.insert(op.address.clone(), delegator.clone())?;
+    }
+
     Ok(pubkey)
 }
 
@@ -0,0 +1,212 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use abcf::{bs3::MapStore, tm_protos::abci::RequestBeginBlock};
+use libfindora::{
+    staking::{TendermintAddress, Unjail, ValidatorPublicKey},
+    Address,
+};
+
+use crate::{Error, Power, Result, FRA_STAKING};
+
+use super::{BlockEvidence, ByzantineKind};
+
+/// Power sent to tendermint, jailed validator has no power.
+pub fn vote_power(
+    validator: &TendermintAddress,
+    power: Power,
+    jailed: &impl MapStore<TendermintAddress, i64>,
+) -> Result<i64> {
+    if jailed.get(validator)?.is_some() {
+        Ok(0)
+    } else {
+        Ok(power.try_into()?)
+    }
+}
+
+/// Validators to jail in this block.
+///
+/// Include validators with duplicate vote, or missed `jail_missed_block` blocks in a row.
+pub fn check_jail(
+    req: &RequestBeginBlock,
+    evidences: &BlockEvidence,
+    missed_blocks: &mut impl MapStore<TendermintAddress, u64>,
+) -> Result<BTreeSet<TendermintAddress>> {
+    let mut res = BTreeSet::new();
+
+    if let Some(lci) = &req.last_commit_info {
+        for vote in &lci.votes {
+            if !vote.signed_last_block {
+                continue;
+            }
+
+            if let Some(validator) = &vote.validator {
+                missed_blocks.remove(&TendermintAddress::from(&validator.address))?;
+            }
+        }
+    }
+
+    for evidence in &evidences.evidences {
+        let validator = match &evidence.validator {
+            Some(v) => v,
+            None => continue,
+        };
+
+        match evidence.kind {
+            ByzantineKind::DuplicateVote => {
+                res.insert(validator.clone());
+            }
+            ByzantineKind::OffLine => {
+                let missed = if let Some(m) = missed_blocks.get_mut(validator)? {
+                    *m += 1;
+                    *m
+                } else {
+                    missed_blocks.insert(validator.clone(), 1)?;
+                    1
+                };
+
+                if missed >= FRA_STAKING.jail_missed_block {
+                    missed_blocks.remove(validator)?;
+                    res.insert(validator.clone());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(res)
+}
+
+/// Mark validators jailed, return power update to remove them from active set.
+pub fn apply_jail(
+    height: i64,
+    validators: &BTreeSet<TendermintAddress>,
+    jailed: &mut impl MapStore<TendermintAddress, i64>,
+    validator_pubkey: &impl MapStore<TendermintAddress, ValidatorPublicKey>,
+) -> Result<BTreeMap<ValidatorPublicKey, i64>> {
+    let mut res = BTreeMap::new();
+
+    for validator in validators {
+        let pubkey = match validator_pubkey.get(validator)? {
+            Some(k) => k.clone(),
+            None => continue,
+        };
+
+        if jailed.get(validator)?.is_none() {
+            jailed.insert(validator.clone(), height + FRA_STAKING.jail_block)?;
+        }
+
+        res.insert(pubkey, 0);
+    }
+
+    Ok(res)
+}
+
+/// Return jailed validator to active set with its power.
+#[allow(clippy::too_many_arguments)]
+pub fn apply_unjail(
+    height: i64,
+    signers: &BTreeSet<Address>,
+    op: &Unjail,
+    validator_staker: &impl MapStore<TendermintAddress, Address>,
+    validator_pubkey: &impl MapStore<TendermintAddress, ValidatorPublicKey>,
+    powers: &impl MapStore<TendermintAddress, Power>,
+    jailed: &mut impl MapStore<TendermintAddress, i64>,
+    missed_blocks: &mut impl MapStore<TendermintAddress, u64>,
+) -> Result<(ValidatorPublicKey, i64)> {
+    let staker = validator_staker
+        .get(&op.address)?
+        .ok_or(Error::MustDoSelfDegegateFirst)?
+        .clone();
+
+    if !signers.contains(&staker) {
+        return Err(Error::MustSignedByStaker(staker));
+    }
+
+    let until = *jailed.get(&op.address)?.ok_or(Error::NotJailed)?;
+
+    if height < until {
+        return Err(Error::JailCooldown(until));
+    }
+
+    jailed.remove(&op.address)?;
+    missed_blocks.remove(&op.address)?;
+
+    let pubkey = validator_pubkey
+        .get(&op.address)?
+        .ok_or(Error::MustDoSelfDegegateFirst)?
+        .clone();
+
+    let power = powers.get(&op.address)?.map(|p| *p).unwrap_or_default();
+
+    Ok((pubkey, power.try_into()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::{address, store, validator, Store};
+    use abcf::bs3::model::Map;
+
+    #[test]
+    fn test_jail_and_unjail() {
+        let mut jailed: Store<Map<TendermintAddress, i64>> = store!();
+        let mut missed_blocks: Store<Map<TendermintAddress, u64>> = store!();
+        let mut validator_staker: Store<Map<TendermintAddress, Address>> = store!();
+        let mut validator_pubkey: Store<Map<TendermintAddress, ValidatorPublicKey>> = store!();
+        let mut powers: Store<Map<TendermintAddress, Power>> = store!();
+
+        let pubkey = ValidatorPublicKey::Ed25519(vec![1; 32]);
+        validator_staker.insert(validator(1), address(1)).unwrap();
+        validator_pubkey
+            .insert(validator(1), pubkey.clone())
+            .unwrap();
+        powers.insert(validator(1), 100).unwrap();
+        missed_blocks.insert(validator(1), 3).unwrap();
+
+        let updates = apply_jail(
+            100,
+            &BTreeSet::from([validator(1)]),
+            &mut jailed,
+            &validator_pubkey,
+        )
+        .unwrap();
+
+        let until = 100 + FRA_STAKING.jail_block;
+        assert_eq!(updates, BTreeMap::from([(pubkey.clone(), 0)]));
+        assert_eq!(jailed.get(&validator(1)).unwrap().map(|h| *h), Some(until));
+        assert_eq!(vote_power(&validator(1), 100, &jailed).unwrap(), 0);
+        assert_eq!(vote_power(&validator(2), 100, &jailed).unwrap(), 100);
+
+        let op = Unjail {
+            address: validator(1),
+        };
+        let signers = BTreeSet::from([address(1)]);
+
+        let mut unjail = |height: i64, signers: &BTreeSet<Address>| {
+            apply_unjail(
+                height,
+                signers,
+                &op,
+                &validator_staker,
+                &validator_pubkey,
+                &powers,
+                &mut jailed,
+                &mut missed_blocks,
+            )
+        };
+
+        assert!(matches!(
+            unjail(until, &BTreeSet::new()),
+            Err(Error::MustSignedByStaker(_))
+        ));
+        assert!(matches!(
+            unjail(until - 1, &signers),
+            Err(Error::JailCooldown(h)) if h == until
+        ));
+        assert_eq!(unjail(until, &signers).unwrap(), (pubkey, 100));
+        assert!(matches!(unjail(until, &signers), Err(Error::NotJailed)));
+
+        assert!(jailed.get(&validator(1)).unwrap().is_none());
+        assert!(missed_blocks.get(&validator(1)).unwrap().is_none());
+    }
+}
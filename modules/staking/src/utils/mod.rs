@@ -9,3 +9,18 @@ pub use kind::*;
 
 mod penalty;
 pub use penalty::*;
+
+mod profile;
+pub use profile::*;
+
+mod unbonding;
+pub use unbonding::*;
+
+mod redelegate;
+pub use redelegate::*;
+
+mod jail;
+pub use jail::*;
+
+#[cfg(test)]
+mod test_utils;
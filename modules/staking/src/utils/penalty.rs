@@ -9,9 +9,9 @@ use libfindora::{
 
 use crate::{Error, Power, Result};
 
-use super::{BlockEvidence, ByzantineKind};
+use super::{penalty_redelegations, vote_power, BlockEvidence, ByzantineKind, Redelegation};
 
-fn compute_penalty_amount(amount: Amount, rate: [u64; 2]) -> Option<Amount> {
+pub(crate) fn compute_penalty_amount(amount: Amount, rate: [u64; 2]) -> Option<Amount> {
     let upper = amount.checked_mul(rate[0])?;
     upper.checked_div(rate[1])
 }
@@ -64,7 +64,9 @@ pub fn penalty_single(
     Ok(power.try_into()?)
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn penalty(
+    height: i64,
     evidences: &BlockEvidence,
     // validator_pubkey: &mut impl MapStore<TendermintAddress, ValidatorPublicKey>,
     validator_powers: &mut impl MapStore<TendermintAddress, Power>,
@@ -73,6 +75,8 @@ pub fn penalty(
     delegators: &mut impl MapStore<TendermintAddress, BTreeMap<Address, Amount>>,
     validator_staker: &mut impl MapStore<TendermintAddress, Address>,
     validator_pubkey: &mut impl MapStore<TendermintAddress, ValidatorPublicKey>,
+    redelegations: &mut impl MapStore<TendermintAddress, Vec<Redelegation>>,
+    jailed: &impl MapStore<TendermintAddress, i64>,
 ) -> Result<BTreeMap<ValidatorPublicKey, i64>> {
     let mut res = BTreeMap::new();
 
@@ -88,6 +92,8 @@ pub fn penalty(
             continue;
         };
 
+        let rate = evidence.kind.penalty_rate();
+
         let power = penalty_single(
             evidence_validator_address,
             evidence.kind.clone(),
@@ -99,8 +105,31 @@ pub fn penalty(
         )?;
 
         if let Some(pubkey) = validator_pubkey.get(evidence_validator_address)? {
+            // Jailed validator keeps no power in tendermint.
+            let power = if jailed.get(evidence_validator_address)?.is_some() {
+                0
+            } else {
+                power
+            };
             res.insert(pubkey.clone(), power);
         }
+
+        let addr_power_vec = penalty_redelegations(
+            height,
+            evidence_validator_address,
+            rate,
+            redelegations,
+            delegators,
+            validator_powers,
+            global_power,
+            delegation_amount,
+        )?;
+
+        for (addr, power) in addr_power_vec {
+            if let Some(pubkey) = validator_pubkey.get(&addr)? {
+                res.insert(pubkey.clone(), vote_power(&addr, power, jailed)?);
+            }
+        }
     }
 
     Ok(res)
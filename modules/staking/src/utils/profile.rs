@@ -0,0 +1,216 @@
+use std::collections::BTreeSet;
+
+use abcf::bs3::MapStore;
+use libfindora::{
+    staking::{
+        Delegate, TendermintAddress, UpdateValidator, ValidatorProfile, COMMISSION_RATE_DENOMINATOR,
+    },
+    Address,
+};
+
+use crate::{Error, Result, FRA_STAKING};
+
+pub const MAX_NAME_LENGTH: usize = 64;
+pub const MAX_WEBSITE_LENGTH: usize = 128;
+pub const MAX_DESCRIPTION_LENGTH: usize = 512;
+
+pub fn check_profile(profile: &ValidatorProfile) -> Result<()> {
+    if profile.name.len() > MAX_NAME_LENGTH {
+        return Err(Error::InvalidProfile(format!(
+            "name longer than {}",
+            MAX_NAME_LENGTH
+        )));
+    }
+
+    if profile.website.len() > MAX_WEBSITE_LENGTH {
+        return Err(Error::InvalidProfile(format!(
+            "website longer than {}",
+            MAX_WEBSITE_LENGTH
+        )));
+    }
+
+    if profile.description.len() > MAX_DESCRIPTION_LENGTH {
+        return Err(Error::InvalidProfile(format!(
+            "description longer than {}",
+            MAX_DESCRIPTION_LENGTH
+        )));
+    }
+
+    if profile.commission_rate > COMMISSION_RATE_DENOMINATOR {
+        return Err(Error::InvalidProfile(format!(
+            "commission rate greater than {}",
+            COMMISSION_RATE_DENOMINATOR
+        )));
+    }
+
+    Ok(())
+}
+
+/// Commission rate of validator, default rate if no profile.
+pub fn commission_rate(
+    validator: &TendermintAddress,
+    validator_profile: &impl MapStore<TendermintAddress, ValidatorProfile>,
+) -> Result<u64> {
+    Ok(validator_profile
+        .get(validator)?
+        .map(|p| p.commission_rate)
+        .unwrap_or(FRA_STAKING.default_commission_rate))
+}
+
+/// Store profile carried by self-delegation.
+pub fn apply_profile(
+    op: &Delegate,
+    validator_profile: &mut impl MapStore<TendermintAddress, ValidatorProfile>,
+) -> Result<()> {
+    if let Some(profile) = &op.profile {
+        if op.validator.is_none() {
+            return Err(Error::ProfileMustSelfDelegate);
+        }
+
+        check_profile(profile)?;
+
+        validator_profile.insert(op.address.clone(), profile.clone())?;
+    }
+
+    Ok(())
+}
+
+/// Update profile of validator.
+///
+/// Commission rate can move at most `max_commission_change` away from
+/// the rate at the first change of current epoch.
+pub fn apply_update_validator(
+    height: i64,
+    signers: &BTreeSet<Address>,
+    op: &UpdateValidator,
+    validator_staker: &impl MapStore<TendermintAddress, Address>,
+    validator_profile: &mut impl MapStore<TendermintAddress, ValidatorProfile>,
+    commission_epoch: &mut impl MapStore<TendermintAddress, (i64, u64)>,
+) -> Result<()> {
+    let staker = validator_staker
+        .get(&op.address)?
+        .ok_or(Error::MustDoSelfDegegateFirst)?
+        .clone();
+
+    if !signers.contains(&staker) {
+        return Err(Error::MustSignedByStaker(staker));
+    }
+
+    check_profile(&op.profile)?;
+
+    let current = commission_rate(&op.address, validator_profile)?;
+
+    if current != op.profile.commission_rate {
+        let epoch = height / FRA_STAKING.epoch_block;
+
+        let base = match commission_epoch.get(&op.address)? {
+            Some(e) if e.0 == epoch => e.1,
+            _ => current,
+        };
+
+        let change = if op.profile.commission_rate > base {
+            op.profile.commission_rate - base
+        } else {
+            base - op.profile.commission_rate
+        };
+
+        if change > FRA_STAKING.max_commission_change {
+            return Err(Error::CommissionChangeTooLarge(
+                base,
+                FRA_STAKING.max_commission_change,
+            ));
+        }
+
+        commission_epoch.insert(op.address.clone(), (epoch, base))?;
+    }
+
+    validator_profile.insert(op.address.clone(), op.profile.clone())?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::{address, store, validator, Store};
+    use abcf::bs3::model::Map;
+
+    fn update(commission_rate: u64) -> UpdateValidator {
+        UpdateValidator {
+            address: validator(1),
+            profile: ValidatorProfile {
+                commission_rate,
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn test_check_profile() {
+        assert!(check_profile(&update(COMMISSION_RATE_DENOMINATOR).profile).is_ok());
+        assert!(matches!(
+            check_profile(&update(COMMISSION_RATE_DENOMINATOR + 1).profile),
+            Err(Error::InvalidProfile(_))
+        ));
+    }
+
+    #[test]
+    fn test_update_validator_commission_epoch() {
+        let mut validator_staker: Store<Map<TendermintAddress, Address>> = store!();
+        let mut validator_profile: Store<Map<TendermintAddress, ValidatorProfile>> = store!();
+        let mut commission_epoch: Store<Map<TendermintAddress, (i64, u64)>> = store!();
+
+        let signers = BTreeSet::from([address(1)]);
+        let default_rate = FRA_STAKING.default_commission_rate;
+        let max_change = FRA_STAKING.max_commission_change;
+
+        let res = apply_update_validator(
+            0,
+            &signers,
+            &update(default_rate),
+            &validator_staker,
+            &mut validator_profile,
+            &mut commission_epoch,
+        );
+        assert!(matches!(res, Err(Error::MustDoSelfDegegateFirst)));
+
+        validator_staker.insert(validator(1), address(1)).unwrap();
+
+        let mut apply = |height: i64, signers: &BTreeSet<Address>, rate: u64| {
+            apply_update_validator(
+                height,
+                signers,
+                &update(rate),
+                &validator_staker,
+                &mut validator_profile,
+                &mut commission_epoch,
+            )
+        };
+
+        assert!(matches!(
+            apply(0, &BTreeSet::new(), default_rate),
+            Err(Error::MustSignedByStaker(_))
+        ));
+
+        // Changes in one epoch are bounded by rate at start of epoch.
+        apply(0, &signers, default_rate + max_change).unwrap();
+        assert!(matches!(
+            apply(1, &signers, default_rate + max_change + 1),
+            Err(Error::CommissionChangeTooLarge(base, _)) if base == default_rate
+        ));
+        apply(1, &signers, default_rate - max_change).unwrap();
+
+        // Next epoch starts from current rate.
+        let next = FRA_STAKING.epoch_block;
+        apply(next, &signers, default_rate).unwrap();
+        assert!(matches!(
+            apply(next, &signers, default_rate + max_change + 1),
+            Err(Error::CommissionChangeTooLarge(base, _)) if base == default_rate - max_change
+        ));
+
+        assert_eq!(
+            commission_rate(&validator(1), &validator_profile).unwrap(),
+            default_rate
+        );
+    }
+}
@@ -0,0 +1,260 @@
+use std::collections::BTreeMap;
+
+use abcf::bs3::{MapStore, ValueStore};
+use libfindora::{
+    asset::Amount,
+    staking::{Delegate, Redelegate, TendermintAddress, Undelegate, ValidatorPublicKey},
+    Address,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{Error, Power, Result, FRA_STAKING};
+
+use super::{apply_detail, apply_global, apply_undelegate_amount, compute_penalty_amount};
+
+/// Redelegated amount, slashed with source validator until `height`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Redelegation {
+    pub delegator: Address,
+    pub to: TendermintAddress,
+    pub amount: Amount,
+    pub height: i64,
+}
+
+/// Move amount of delegator from validator to another.
+///
+/// Return power of both validators.
+#[allow(clippy::too_many_arguments)]
+pub fn apply_redelegate(
+    height: i64,
+    delegator: &Address,
+    amount: Amount,
+    op: &Redelegate,
+    delegators: &mut impl MapStore<TendermintAddress, BTreeMap<Address, Amount>>,
+    global_power: &mut impl ValueStore<Power>,
+    powers: &mut impl MapStore<TendermintAddress, Power>,
+    delegation_amount: &mut impl MapStore<Address, Amount>,
+    validator_pubkey: &impl MapStore<TendermintAddress, ValidatorPublicKey>,
+    redelegations: &mut impl MapStore<TendermintAddress, Vec<Redelegation>>,
+) -> Result<Vec<(TendermintAddress, Power)>> {
+    if op.from == op.to {
+        return Err(Error::RedelegateSameValidator);
+    }
+
+    if validator_pubkey.get(&op.to)?.is_none() {
+        return Err(Error::MustDoSelfDegegateFirst);
+    }
+
+    let undelegate = Undelegate {
+        address: op.from.clone(),
+    };
+
+    let mut addr_power_vec = apply_undelegate_amount(
+        amount,
+        delegator,
+        &undelegate,
+        delegators,
+        global_power,
+        powers,
+        delegation_amount,
+    )?;
+
+    let delegate = Delegate {
+        address: op.to.clone(),
+        validator: None,
+        memo: None,
+        profile: None,
+    };
+
+    let power = apply_global(amount, &delegate, global_power, powers)?;
+    addr_power_vec.push((op.to.clone(), power));
+
+    apply_detail(delegator, amount, &delegate, delegators, delegation_amount)?;
+
+    let redelegation = Redelegation {
+        delegator: delegator.clone(),
+        to: op.to.clone(),
+        amount,
+        height: height + FRA_STAKING.undelegate_block,
+    };
+
+    if let Some(v) = redelegations.get_mut(&op.from)? {
+        v.retain(|r| r.height > height);
+        v.push(redelegation);
+    } else {
+        redelegations.insert(op.from.clone(), vec![redelegation])?;
+    }
+
+    Ok(addr_power_vec)
+}
+
+/// Slash amount redelegated away from validator in unbonding window.
+///
+/// Return power of destination validators.
+#[allow(clippy::too_many_arguments)]
+pub fn penalty_redelegations(
+    height: i64,
+    validator: &TendermintAddress,
+    rate: [u64; 2],
+    redelegations: &mut impl MapStore<TendermintAddress, Vec<Redelegation>>,
+    delegators: &mut impl MapStore<TendermintAddress, BTreeMap<Address, Amount>>,
+    validator_powers: &mut impl MapStore<TendermintAddress, Power>,
+    global_power: &mut impl ValueStore<Power>,
+    delegation_amount: &mut impl MapStore<Address, Amount>,
+) -> Result<Vec<(TendermintAddress, Power)>> {
+    let mut addr_power_vec = Vec::new();
+
+    let entries = if let Some(v) = redelegations.get_mut(validator)? {
+        v.retain(|r| r.height > height);
+        v
+    } else {
+        return Ok(addr_power_vec);
+    };
+
+    for entry in entries.iter_mut() {
+        let mut penalty = compute_penalty_amount(entry.amount, rate).unwrap_or_default();
+
+        // Can't slash more than still delegated to destination.
+        if let Some(map) = delegators.get_mut(&entry.to)? {
+            if let Some(a) = map.get_mut(&entry.delegator) {
+                penalty = penalty.min(*a);
+                *a -= penalty;
+            } else {
+                continue;
+            }
+        } else {
+            continue;
+        }
+
+        entry.amount = entry.amount.saturating_sub(penalty);
+
+        if let Some(p) = validator_powers.get_mut(&entry.to)? {
+            *p = p.saturating_sub(penalty);
+            addr_power_vec.push((entry.to.clone(), *p));
+        }
+
+        if let Some(gp) = global_power.get()? {
+            let gp = gp.saturating_sub(penalty);
+            global_power.set(gp)?;
+        }
+
+        if let Some(a) = delegation_amount.get_mut(&entry.delegator)? {
+            *a = a.saturating_sub(penalty);
+        }
+    }
+
+    Ok(addr_power_vec)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::{address, store, validator, Store};
+    use abcf::bs3::model::{Map, Value};
+
+    #[test]
+    fn test_redelegate_invalid() {
+        let mut delegators: Store<Map<TendermintAddress, BTreeMap<Address, Amount>>> = store!();
+        let mut global_power: Store<Value<Power>> = store!();
+        let mut powers: Store<Map<TendermintAddress, Power>> = store!();
+        let mut delegation_amount: Store<Map<Address, Amount>> = store!();
+        let validator_pubkey: Store<Map<TendermintAddress, ValidatorPublicKey>> = store!();
+        let mut redelegations: Store<Map<TendermintAddress, Vec<Redelegation>>> = store!();
+
+        let mut redelegate = |to: TendermintAddress| {
+            let op = Redelegate {
+                from: validator(1),
+                to,
+            };
+
+            apply_redelegate(
+                0,
+                &address(1),
+                100,
+                &op,
+                &mut delegators,
+                &mut global_power,
+                &mut powers,
+                &mut delegation_amount,
+                &validator_pubkey,
+                &mut redelegations,
+            )
+        };
+
+        assert!(matches!(
+            redelegate(validator(1)),
+            Err(Error::RedelegateSameValidator)
+        ));
+        assert!(matches!(
+            redelegate(validator(2)),
+            Err(Error::MustDoSelfDegegateFirst)
+        ));
+    }
+
+    #[test]
+    fn test_penalty_redelegations() {
+        let mut redelegations: Store<Map<TendermintAddress, Vec<Redelegation>>> = store!();
+        let mut delegators: Store<Map<TendermintAddress, BTreeMap<Address, Amount>>> = store!();
+        let mut powers: Store<Map<TendermintAddress, Power>> = store!();
+        let mut global_power: Store<Value<Power>> = store!();
+        let mut delegation_amount: Store<Map<Address, Amount>> = store!();
+
+        redelegations
+            .insert(
+                validator(1),
+                vec![Redelegation {
+                    delegator: address(1),
+                    to: validator(2),
+                    amount: 100,
+                    height: 15,
+                }],
+            )
+            .unwrap();
+        delegators
+            .insert(validator(2), BTreeMap::from([(address(1), 100)]))
+            .unwrap();
+        powers.insert(validator(2), 100).unwrap();
+        global_power.set(1000).unwrap();
+        delegation_amount.insert(address(1), 100).unwrap();
+
+        let mut penalty = |height: i64| {
+            penalty_redelegations(
+                height,
+                &validator(1),
+                [1, 10],
+                &mut redelegations,
+                &mut delegators,
+                &mut powers,
+                &mut global_power,
+                &mut delegation_amount,
+            )
+            .unwrap()
+        };
+
+        // Slashed with source validator in unbonding window.
+        assert_eq!(penalty(10), vec![(validator(2), 90)]);
+        // Out of unbonding window.
+        assert!(penalty(15).is_empty());
+
+        assert_eq!(
+            delegators
+                .get(&validator(2))
+                .unwrap()
+                .unwrap()
+                .get(&address(1))
+                .copied(),
+            Some(90)
+        );
+        assert_eq!(powers.get(&validator(2)).unwrap().map(|p| *p), Some(90));
+        assert_eq!(global_power.get().unwrap().map(|p| *p), Some(990));
+        assert_eq!(
+            delegation_amount.get(&address(1)).unwrap().map(|a| *a),
+            Some(90)
+        );
+        assert!(redelegations
+            .get(&validator(1))
+            .unwrap()
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,24 @@
+use abcf::bs3::{backend::MemoryBackend, SnapshotableStorage};
+use libfindora::{staking::TendermintAddress, Address};
+
+/// In-memory store of model `T`.
+pub type Store<T> = SnapshotableStorage<MemoryBackend, T>;
+
+macro_rules! store {
+    () => {
+        abcf::bs3::SnapshotableStorage::new(
+            Default::default(),
+            abcf::bs3::backend::MemoryBackend::new(),
+        )
+        .unwrap()
+    };
+}
+pub(crate) use store;
+
+pub fn validator(n: u8) -> TendermintAddress {
+    TendermintAddress([n; 20])
+}
+
+pub fn address(n: u8) -> Address {
+    Address::from(&[n; 20][..])
+}
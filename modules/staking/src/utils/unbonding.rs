@@ -0,0 +1,277 @@
+use std::collections::BTreeSet;
+
+use abcf::bs3::{MapStore, ValueStore};
+use libfindora::{
+    asset::Amount,
+    staking::{CancelUnbonding, TendermintAddress, UnbondingEntry, UnbondingStatus},
+    Address,
+};
+
+use crate::{Error, Result};
+
+/// Record undelegated amount as pending entry, completed at `height`.
+#[allow(clippy::too_many_arguments)]
+pub fn apply_unbonding(
+    delegator: &Address,
+    validator: &TendermintAddress,
+    amount: Amount,
+    height: i64,
+    unbonding: &mut impl MapStore<u64, UnbondingEntry>,
+    unbonding_count: &mut impl ValueStore<u64>,
+    unbonding_queue: &mut impl MapStore<i64, Vec<u64>>,
+    delegator_unbonding: &mut impl MapStore<Address, BTreeSet<u64>>,
+) -> Result<u64> {
+    let id = unbonding_count.get()?.map(|v| *v).unwrap_or_default();
+
+    let entry = UnbondingEntry {
+        delegator: delegator.clone(),
+        validator: validator.clone(),
+        amount,
+        height,
+        status: UnbondingStatus::Pending,
+    };
+
+    unbonding.insert(id, entry)?;
+    unbonding_count.set(id.checked_add(1).ok_or(Error::OverflowAdd)?)?;
+
+    if let Some(ids) = unbonding_queue.get_mut(&height)? {
+        ids.push(id);
+    } else {
+        unbonding_queue.insert(height, vec![id])?;
+    }
+
+    if let Some(ids) = delegator_unbonding.get_mut(delegator)? {
+        ids.insert(id);
+    } else {
+        let mut ids = BTreeSet::new();
+        ids.insert(id);
+        delegator_unbonding.insert(delegator.clone(), ids)?;
+    }
+
+    Ok(id)
+}
+
+/// Cancel pending entry before completion, return entry to delegate back.
+pub fn cancel_unbonding(
+    height: i64,
+    signers: &BTreeSet<Address>,
+    op: &CancelUnbonding,
+    unbonding: &mut impl MapStore<u64, UnbondingEntry>,
+    unbonding_queue: &mut impl MapStore<i64, Vec<u64>>,
+) -> Result<UnbondingEntry> {
+    let entry = unbonding
+        .get_mut(&op.id)?
+        .ok_or(Error::UnbondingNotFound(op.id))?;
+
+    if !signers.contains(&entry.delegator) {
+        return Err(Error::MustSignedByDelegator(entry.delegator.clone()));
+    }
+
+    if entry.status != UnbondingStatus::Pending || height >= entry.height {
+        return Err(Error::UnbondingNotPending(op.id));
+    }
+
+    entry.status = UnbondingStatus::Cancelled;
+
+    let entry = entry.clone();
+
+    if let Some(ids) = unbonding_queue.get_mut(&entry.height)? {
+        ids.retain(|id| *id != op.id);
+    }
+
+    Ok(entry)
+}
+
+/// Complete entries at `height`, each entry is paid by `payout` before marked completed.
+///
+/// Queue slot is removed after all entries are paid, so error leaves unpaid
+/// entries pending.
+pub fn complete_unbonding(
+    height: i64,
+    unbonding: &mut impl MapStore<u64, UnbondingEntry>,
+    unbonding_queue: &mut impl MapStore<i64, Vec<u64>>,
+    mut payout: impl FnMut(&UnbondingEntry) -> Result<()>,
+) -> Result<Vec<u64>> {
+    let mut completed = Vec::new();
+
+    let ids = match unbonding_queue.get(&height)? {
+        Some(ids) => ids.clone(),
+        None => return Ok(completed),
+    };
+
+    for id in ids {
+        if let Some(entry) = unbonding.get_mut(&id)? {
+            if entry.status == UnbondingStatus::Pending {
+                payout(&*entry)?;
+                entry.status = UnbondingStatus::Completed;
+                completed.push(id);
+            }
+        }
+    }
+
+    unbonding_queue.remove(&height)?;
+
+    Ok(completed)
+}
+
+pub fn get_unbonding(
+    id: u64,
+    unbonding: &impl MapStore<u64, UnbondingEntry>,
+) -> Result<UnbondingEntry> {
+    let entry = unbonding.get(&id)?.ok_or(Error::UnbondingNotFound(id))?;
+
+    Ok(entry.clone())
+}
+
+pub fn get_delegator_unbonding(
+    delegator: &Address,
+    unbonding: &impl MapStore<u64, UnbondingEntry>,
+    delegator_unbonding: &impl MapStore<Address, BTreeSet<u64>>,
+) -> Result<Vec<(u64, UnbondingEntry)>> {
+    let mut entries = Vec::new();
+
+    if let Some(ids) = delegator_unbonding.get(delegator)? {
+        for id in ids.iter() {
+            if let Some(entry) = unbonding.get(id)? {
+                entries.push((*id, entry.clone()));
+            }
+        }
+    }
+
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::{address, store, validator, Store};
+    use abcf::bs3::model::{Map, Value};
+
+    struct Stores {
+        unbonding: Store<Map<u64, UnbondingEntry>>,
+        unbonding_count: Store<Value<u64>>,
+        unbonding_queue: Store<Map<i64, Vec<u64>>>,
+        delegator_unbonding: Store<Map<Address, BTreeSet<u64>>>,
+    }
+
+    impl Stores {
+        fn new() -> Self {
+            Self {
+                unbonding: store!(),
+                unbonding_count: store!(),
+                unbonding_queue: store!(),
+                delegator_unbonding: store!(),
+            }
+        }
+
+        fn apply(&mut self, amount: Amount, height: i64) -> u64 {
+            apply_unbonding(
+                &address(1),
+                &validator(1),
+                amount,
+                height,
+                &mut self.unbonding,
+                &mut self.unbonding_count,
+                &mut self.unbonding_queue,
+                &mut self.delegator_unbonding,
+            )
+            .unwrap()
+        }
+
+        fn status(&self, id: u64) -> UnbondingStatus {
+            get_unbonding(id, &self.unbonding).unwrap().status
+        }
+    }
+
+    #[test]
+    fn test_complete_unbonding() {
+        let mut s = Stores::new();
+
+        assert_eq!(s.apply(100, 10), 0);
+        assert_eq!(s.apply(50, 10), 1);
+        assert_eq!(s.apply(30, 12), 2);
+
+        let mut paid = Vec::new();
+        let completed = complete_unbonding(10, &mut s.unbonding, &mut s.unbonding_queue, |e| {
+            paid.push(e.amount);
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(completed, vec![0, 1]);
+        assert_eq!(paid, vec![100, 50]);
+        assert_eq!(s.status(0), UnbondingStatus::Completed);
+        assert_eq!(s.status(1), UnbondingStatus::Completed);
+        assert_eq!(s.status(2), UnbondingStatus::Pending);
+
+        // Paid out only once.
+        let completed = complete_unbonding(10, &mut s.unbonding, &mut s.unbonding_queue, |e| {
+            paid.push(e.amount);
+            Ok(())
+        })
+        .unwrap();
+
+        assert!(completed.is_empty());
+        assert_eq!(paid, vec![100, 50]);
+
+        let entries =
+            get_delegator_unbonding(&address(1), &s.unbonding, &s.delegator_unbonding).unwrap();
+        assert_eq!(entries.len(), 3);
+    }
+
+    #[test]
+    fn test_complete_unbonding_payout_failed() {
+        let mut s = Stores::new();
+
+        s.apply(100, 10);
+
+        let res = complete_unbonding(10, &mut s.unbonding, &mut s.unbonding_queue, |_| {
+            Err(Error::OverflowAdd)
+        });
+
+        assert!(matches!(res, Err(Error::OverflowAdd)));
+        assert_eq!(s.status(0), UnbondingStatus::Pending);
+        assert!(s.unbonding_queue.get(&10).unwrap().is_some());
+
+        let completed =
+            complete_unbonding(10, &mut s.unbonding, &mut s.unbonding_queue, |_| Ok(())).unwrap();
+
+        assert_eq!(completed, vec![0]);
+        assert_eq!(s.status(0), UnbondingStatus::Completed);
+    }
+
+    #[test]
+    fn test_cancel_unbonding() {
+        let mut s = Stores::new();
+
+        let id = s.apply(100, 10);
+        let op = CancelUnbonding { id };
+        let signers = BTreeSet::from([address(1)]);
+
+        let res = cancel_unbonding(
+            5,
+            &BTreeSet::new(),
+            &op,
+            &mut s.unbonding,
+            &mut s.unbonding_queue,
+        );
+        assert!(matches!(res, Err(Error::MustSignedByDelegator(_))));
+
+        // Too late to cancel at completion height.
+        let res = cancel_unbonding(10, &signers, &op, &mut s.unbonding, &mut s.unbonding_queue);
+        assert!(matches!(res, Err(Error::UnbondingNotPending(0))));
+
+        let entry =
+            cancel_unbonding(5, &signers, &op, &mut s.unbonding, &mut s.unbonding_queue).unwrap();
+        assert_eq!(entry.amount, 100);
+        assert_eq!(s.status(id), UnbondingStatus::Cancelled);
+
+        let res = cancel_unbonding(5, &signers, &op, &mut s.unbonding, &mut s.unbonding_queue);
+        assert!(matches!(res, Err(Error::UnbondingNotPending(0))));
+
+        // Cancelled entry isn't paid out.
+        let completed =
+            complete_unbonding(10, &mut s.unbonding, &mut s.unbonding_queue, |_| Ok(())).unwrap();
+        assert!(completed.is_empty());
+    }
+}
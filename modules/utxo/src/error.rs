@@ -1,4 +1,8 @@
-use libfindora::utxo::OutputId;
+use libfindora::{
+    utxo::{Lock, OutputId},
+    Address,
+};
+use primitive_types::H512;
 
 #[derive(Debug)]
 pub enum Error {
@@ -9,6 +13,16 @@ pub enum Error {
     DuplicateOutput(OutputId),
     MissingOutput(OutputId),
     RucError(Box<dyn ruc::RucError>),
+    MissingSignature(Address, OutputId),
+    DuplicateInput(OutputId),
+    PendingSpent(OutputId, H512),
+    LockedOutput(OutputId, Lock),
+    HashLockExpired(OutputId, i64),
+    HashLockNotExpired(OutputId, i64),
+    HashLockPreimageMismatch(OutputId),
+    HashLockRequireUnlock(OutputId),
+    NotHashLock(OutputId),
+    HashLockDeadlinePassed(usize, i64),
 }
 
 impl From<Error> for abcf::Error {
@@ -27,6 +41,51 @@ impl From<Error> for abcf::Error {
             Error::Bs3Error(e) => e.into(),
             Error::TryFromIntError(e) => Self::ABCIApplicationError(90003, format!("{:?}", e)),
             Error::RucError(e) => Self::ABCIApplicationError(90003, format!("{:?}", e)),
+            Error::MissingSignature(a, i) => Self::ABCIApplicationError(
+                90004,
+                format!("Missing signature of owner {:?} for output: {:?}", a, i),
+            ),
+            Error::DuplicateInput(i) => {
+                Self::ABCIApplicationError(90005, format!("Input spent twice: {:?}", i))
+            }
+            Error::PendingSpent(i, txid) => Self::ABCIApplicationError(
+                90006,
+                format!(
+                    "Output {:?} already spent by pending transaction: {:?}",
+                    i, txid
+                ),
+            ),
+            Error::LockedOutput(i, lock) => {
+                Self::ABCIApplicationError(90007, format!("Output {:?} is locked: {:?}", i, lock))
+            }
+            Error::HashLockExpired(i, deadline) => Self::ABCIApplicationError(
+                90008,
+                format!("Hash lock of {:?} expired at height: {}", i, deadline),
+            ),
+            Error::HashLockNotExpired(i, deadline) => Self::ABCIApplicationError(
+                90009,
+                format!(
+                    "Hash lock of {:?} can't refund before height: {}",
+                    i, deadline
+                ),
+            ),
+            Error::HashLockPreimageMismatch(i) => {
+                Self::ABCIApplicationError(90010, format!("Preimage mismatch hash lock of {:?}", i))
+            }
+            Error::HashLockRequireUnlock(i) => Self::ABCIApplicationError(
+                90011,
+                format!("Output {:?} is hash locked, must claim or refund", i),
+            ),
+            Error::NotHashLock(i) => {
+                Self::ABCIApplicationError(90012, format!("Output {:?} isn't hash locked", i))
+            }
+            Error::HashLockDeadlinePassed(index, deadline) => Self::ABCIApplicationError(
+                90013,
+                format!(
+                    "Hash lock of output {} already expired at height: {}",
+                    index, deadline
+                ),
+            ),
         }
     }
 }
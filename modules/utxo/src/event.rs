@@ -1,37 +1,93 @@
+use libfindora::{
+    asset::XfrAssetType,
+    htlc::HashLock,
+    utxo::{Output, OutputId},
+    Address,
+};
+use primitive_types::H256;
 use serde::{Deserialize, Serialize};
-use zei::serialization::ZeiFromToBytes;
-use zei::xfr::structs::BlindAssetRecord;
 
+/// Output is consumed by transaction.
 #[derive(Clone, Debug, Deserialize, Serialize, abcf::Event)]
 pub struct SendEvent {
-    pub_key: String,
-    send_amount: Option<u64>,
+    pub address: Address,
+    pub asset: Option<String>,
+    pub output_id: OutputId,
+    pub send_amount: Option<u64>,
 }
 
 impl SendEvent {
-    pub fn new_from_record(record: &BlindAssetRecord) -> Self {
-        let base64_pub_key = base64::encode(&record.public_key.zei_to_bytes());
-        let send_amount = record.amount.get_amount();
+    pub fn new(output_id: OutputId, output: &Output) -> Self {
         Self {
-            pub_key: base64_pub_key,
-            send_amount,
+            address: output.address.clone(),
+            asset: asset_base64(&output.asset),
+            output_id,
+            send_amount: output.amount.get_amount(),
         }
     }
 }
 
+/// Output is created by transaction.
 #[derive(Clone, Debug, Deserialize, Serialize, abcf::Event)]
 pub struct RecvEvent {
-    pub_key: String,
-    recv_amount: Option<u64>,
+    pub address: Address,
+    pub asset: Option<String>,
+    pub output_id: OutputId,
+    pub recv_amount: Option<u64>,
 }
 
 impl RecvEvent {
-    pub fn new_from_record(record: &BlindAssetRecord) -> Self {
-        let base64_pub_key = base64::encode(&record.public_key.zei_to_bytes());
-        let recv_amount = record.amount.get_amount();
+    pub fn new(output_id: OutputId, output: &Output) -> Self {
         Self {
-            pub_key: base64_pub_key,
-            recv_amount,
+            address: output.address.clone(),
+            asset: asset_base64(&output.asset),
+            output_id,
+            recv_amount: output.amount.get_amount(),
         }
     }
 }
+
+/// Asset type in url safe base64, none if confidential.
+fn asset_base64(asset: &XfrAssetType) -> Option<String> {
+    match asset {
+        XfrAssetType::NonConfidential(a) => Some(base64::encode_config(a.0, base64::URL_SAFE)),
+        XfrAssetType::Confidential(_) => None,
+    }
+}
+
+/// Output is hash-time-locked.
+#[derive(Clone, Debug, Deserialize, Serialize, abcf::Event)]
+pub struct HashLockEvent {
+    pub output_id: OutputId,
+    pub owner: Address,
+    pub sender: Address,
+    pub hash: H256,
+    pub deadline: i64,
+    pub amount: Option<u64>,
+}
+
+impl HashLockEvent {
+    pub fn new(output_id: OutputId, output: &Output, lock: &HashLock) -> Self {
+        Self {
+            output_id,
+            owner: output.address.clone(),
+            sender: lock.sender.clone(),
+            hash: lock.hash,
+            deadline: lock.deadline,
+            amount: output.amount.get_amount(),
+        }
+    }
+}
+
+/// Hash-time-locked output is claimed by owner, preimage is revealed.
+#[derive(Clone, Debug, Deserialize, Serialize, abcf::Event)]
+pub struct HashClaimEvent {
+    pub output_id: OutputId,
+    pub preimage: String,
+}
+
+/// Hash-time-locked output is refunded to sender.
+#[derive(Clone, Debug, Deserialize, Serialize, abcf::Event)]
+pub struct HashRefundEvent {
+    pub output_id: OutputId,
+}
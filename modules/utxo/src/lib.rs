@@ -5,6 +5,11 @@ pub use module::UtxoModule;
 
 pub mod utils;
 
+mod event;
+pub use event::*;
+
+pub mod rpc;
+
 mod error;
 pub use error::{Error, Result};
 
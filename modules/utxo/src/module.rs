@@ -1,49 +1,128 @@
+use std::collections::BTreeMap;
+
 use abcf::{
-    bs3::{merkle::append_only::AppendOnlyMerkle, model::Map, MapStore},
-    module::types::{RequestDeliverTx, ResponseDeliverTx},
-    Application, TxnContext,
+    bs3::{
+        merkle::append_only::AppendOnlyMerkle,
+        model::{Map, Value},
+        ValueStore,
+    },
+    module::types::{
+        RequestBeginBlock, RequestCheckTx, RequestDeliverTx, RequestEndBlock, ResponseCheckTx,
+        ResponseDeliverTx, ResponseEndBlock,
+    },
+    Application, RPCContext, RPCResponse, TxnContext,
 };
 use libfindora::{
+    htlc::{HashLock, Unlock},
     utxo::{Output, OutputId},
     Address,
 };
+use primitive_types::H512;
 use rand_chacha::ChaChaRng;
 use zei::setup::PublicParams;
 
-use crate::{utils, Transaction};
+use crate::{
+    rpc, utils, utils::OwnedOutputStore, HashClaimEvent, HashLockEvent, HashRefundEvent, RecvEvent,
+    SendEvent, Transaction,
+};
 
 #[abcf::module(name = "utxo", version = 1, impl_version = "0.1.1", target_height = 0)]
 pub struct UtxoModule {
     params: PublicParams,
     prng: ChaChaRng,
+    /// Inputs spent by transactions in mempool, map to the spending txid.
+    ///
+    /// Cleared at end of block, mempool recheck after commit records inputs
+    /// of remaining transactions again.
+    pub pending_inputs: BTreeMap<OutputId, H512>,
     #[stateful(merkle = "AppendOnlyMerkle")]
     pub outputs_set: Map<OutputId, Output>,
+    #[stateful(merkle = "AppendOnlyMerkle")]
+    pub hash_locks: Map<OutputId, HashLock>,
+    /// Height and time, unix timestamp in seconds, from header of current block.
+    #[stateful(merkle = "AppendOnlyMerkle")]
+    pub last_block: Value<(i64, i64)>,
+    /// Slots of outputs owned by address, see `utils::OwnedOutputStore`.
+    #[stateless]
+    pub owned_outputs: Map<(Address, u64), OutputId>,
+    #[stateless]
+    pub owned_outputs_count: Map<Address, u64>,
     #[stateless]
-    pub owned_outputs: Map<Address, Vec<OutputId>>,
+    pub owned_outputs_index: Map<(Address, OutputId), u64>,
+    /// Old layout of owned outputs, migrated when address is written.
+    #[stateless]
+    pub legacy_owned_outputs: Map<Address, Vec<OutputId>>,
 }
 
 #[abcf::rpcs]
-impl UtxoModule {}
+impl UtxoModule {
+    /// Outputs owned by address with their ids, paginated by cursor.
+    pub async fn owned_outputs<'a>(
+        &mut self,
+        ctx: &mut RPCContext<'a, Self>,
+        params: rpc::OwnedOutputsRequest,
+    ) -> RPCResponse<rpc::OwnedOutputsResponse> {
+        let res = utils::query_owned_outputs(
+            &ctx.stateful.outputs_set,
+            &ctx.stateless.owned_outputs,
+            &ctx.stateless.owned_outputs_count,
+            &ctx.stateless.legacy_owned_outputs,
+            &params,
+        );
+
+        match res {
+            Ok(r) => RPCResponse::new(r),
+            Err(e) => abcf::Error::from(e).into(),
+        }
+    }
+}
 
 /// Module's block logic.
 #[abcf::application]
 impl Application for UtxoModule {
     type Transaction = Transaction;
 
-    // async fn check_tx(
-    //     &mut self,
-    //     context: &mut TxnContext<'_, Self>,
-    //     req: &RequestCheckTx<Self::Transaction>,
-    // ) -> abcf::Result<ResponseCheckTx> {
-    //     utils::check_tx(
-    //         &mut self.params,
-    //         &mut self.prng,
-    //         &context.stateful.outputs_set,
-    //         &req.tx,
-    //     )?;
-    //
-    //     Ok(Default::default())
-    // }
+    async fn begin_block(
+        &mut self,
+        context: &mut abcf::AppContext<'_, Self>,
+        req: &RequestBeginBlock,
+    ) {
+        if let Some(header) = &req.header {
+            let time = header.time.as_ref().map(|t| t.seconds).unwrap_or_default();
+
+            if let Err(e) = context.stateful.last_block.set((header.height, time)) {
+                log::error!("set last block failed: {:?}", e);
+            }
+        } else {
+            // TODO: consider panic node.
+            panic!("Got none header, Please restart node.");
+        }
+    }
+
+    async fn check_tx(
+        &mut self,
+        context: &mut TxnContext<'_, Self>,
+        req: &RequestCheckTx<Self::Transaction>,
+    ) -> abcf::Result<ResponseCheckTx> {
+        let tx = &req.tx;
+
+        let (height, time) = utils::last_block(&context.stateful.last_block)?;
+
+        utils::check_tx(
+            &mut self.params,
+            &mut self.prng,
+            &context.stateful.outputs_set,
+            &context.stateful.hash_locks,
+            // Transaction in mempool will be packed into next block, not earlier than last block.
+            height + 1,
+            time,
+            tx,
+        )?;
+
+        utils::insert_pending_inputs(&mut self.pending_inputs, tx)?;
+
+        Ok(Default::default())
+    }
 
     /// Execute transaction on state.
     async fn deliver_tx(
@@ -53,27 +132,81 @@ impl Application for UtxoModule {
     ) -> abcf::Result<ResponseDeliverTx> {
         let tx = &req.tx;
 
-        let owned_outputs = utils::deliver_tx(
+        let (height, time) = utils::last_block(&context.stateful.last_block)?;
+
+        let delivered = utils::deliver_tx(
             &mut self.params,
             &mut self.prng,
             &mut context.stateful.outputs_set,
+            &mut context.stateful.hash_locks,
+            height,
+            time,
             tx,
         )?;
 
-        // TODO: 此代码可优化，倾向于删除同一个地址下的全部输出
-        for (owner, ops) in owned_outputs.into_iter() {
-            if let Some(v) = context.stateless.owned_outputs.get_mut(&owner)? {
-                utils::insert_by_operation(v, ops)?;
-            } else {
-                let mut v = Vec::new();
-                utils::insert_by_operation(&mut v, ops)?;
+        let mut store = utils::OwnedOutputs {
+            outputs: &mut context.stateless.owned_outputs,
+            counts: &mut context.stateless.owned_outputs_count,
+            index: &mut context.stateless.owned_outputs_index,
+            legacy: &mut context.stateless.legacy_owned_outputs,
+        };
 
-                context.stateless.owned_outputs.insert(owner.clone(), v)?;
+        for (owner, ops) in delivered.owned_outputs.into_iter() {
+            store.apply_owned(&owner, ops)?;
+        }
+
+        // Outputs minted by coinbase in this transaction, usually spent by it immediately.
+        for (output_id, output) in &tx.minted {
+            context
+                .events
+                .emit(&RecvEvent::new(output_id.clone(), output))?;
+        }
+
+        for (output_id, output) in delivered.spent {
+            context.events.emit(&SendEvent::new(output_id, &output))?;
+        }
+
+        for (index, output) in tx.outputs.iter().enumerate() {
+            let output_id = OutputId {
+                txid: tx.txid,
+                n: index.try_into().map_err(crate::Error::from)?,
+            };
+            context.events.emit(&RecvEvent::new(output_id, output))?;
+        }
+
+        for (index, lock) in &tx.hash_locks {
+            let output_id = OutputId {
+                txid: tx.txid,
+                n: (*index).try_into().map_err(crate::Error::from)?,
+            };
+            let event = HashLockEvent::new(output_id, &tx.outputs[*index], lock);
+            context.events.emit(&event)?;
+        }
+
+        for (output_id, unlock) in &tx.unlocks {
+            let output_id = output_id.clone();
+            match unlock {
+                Unlock::Claim(preimage) => context.events.emit(&HashClaimEvent {
+                    output_id,
+                    preimage: base64::encode(preimage),
+                })?,
+                Unlock::Refund => context.events.emit(&HashRefundEvent { output_id })?,
             }
         }
 
         Ok(Default::default())
     }
+
+    async fn end_block(
+        &mut self,
+        _context: &mut abcf::AppContext<'_, Self>,
+        _req: &RequestEndBlock,
+    ) -> ResponseEndBlock {
+        // Evicted or failed transactions are not rechecked, so their inputs are released.
+        self.pending_inputs.clear();
+
+        Default::default()
+    }
 }
 
 /// Module's methods.
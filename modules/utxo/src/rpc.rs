@@ -0,0 +1,57 @@
+use libfindora::{
+    asset::{AssetType, XfrAmount, XfrAssetType},
+    utxo::{Output, OutputId},
+    Address,
+};
+use serde::{Deserialize, Serialize};
+
+/// Max slots scanned by one request.
+pub const MAX_OWNED_OUTPUTS_LIMIT: u64 = 1000;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OwnedOutputsRequest {
+    pub address: Address,
+    /// Slot to start scanning, 0 or `next_cursor` of last response.
+    pub cursor: u64,
+    /// Number of slots to scan, clamped to `1..=MAX_OWNED_OUTPUTS_LIMIT`.
+    pub limit: u64,
+    /// Only return outputs of this non-confidential asset type.
+    pub asset: Option<AssetType>,
+    /// Only return confidential outputs if true, non-confidential if false.
+    pub confidential: Option<bool>,
+}
+
+impl OwnedOutputsRequest {
+    pub fn is_match(&self, output: &Output) -> bool {
+        if let Some(asset) = &self.asset {
+            match &output.asset {
+                XfrAssetType::NonConfidential(a) if a == asset => {}
+                _ => return false,
+            }
+        }
+
+        if let Some(confidential) = self.confidential {
+            let is_confidential = matches!(output.amount, XfrAmount::Confidential(_))
+                || matches!(output.asset, XfrAssetType::Confidential(_));
+
+            if is_confidential != confidential {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct OwnedOutput {
+    pub output_id: OutputId,
+    pub output: Output,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct OwnedOutputsResponse {
+    pub outputs: Vec<OwnedOutput>,
+    /// Cursor of next page, none if all slots are scanned.
+    pub next_cursor: Option<u64>,
+}
@@ -1,9 +1,13 @@
 mod validate;
 pub use validate::ValidateTransaction;
 
-use std::convert::TryFrom;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    convert::TryFrom,
+};
 
 use libfindora::{
+    htlc::{HashLock, Unlock},
     utxo::{Output, OutputId},
     Address,
 };
@@ -16,6 +20,19 @@ pub struct Transaction {
     pub inputs: Vec<OutputId>,
     pub outputs: Vec<Output>,
     pub proof: AssetTypeAndAmountProof,
+    /// Addresses which signed this transaction, verified when convert.
+    ///
+    /// Include multi-signature owners which got enough signatures of their keys.
+    pub signers: BTreeSet<Address>,
+    /// Outputs issued by this transaction itself.
+    ///
+    /// Coinbase module mint these outputs before deliver, but in mempool they aren't
+    /// in `outputs_set` yet.
+    pub minted: BTreeMap<OutputId, Output>,
+    /// Spend conditions of hash-time-locked inputs.
+    pub unlocks: BTreeMap<OutputId, Unlock>,
+    /// Hash-time-locks of outputs, keyed by index in `outputs`.
+    pub hash_locks: BTreeMap<usize, HashLock>,
 }
 
 impl Default for Transaction {
@@ -25,6 +42,10 @@ impl Default for Transaction {
             inputs: Vec::new(),
             outputs: Vec::new(),
             proof: AssetTypeAndAmountProof::NoProof,
+            signers: BTreeSet::new(),
+            minted: BTreeMap::new(),
+            unlocks: BTreeMap::new(),
+            hash_locks: BTreeMap::new(),
         }
     }
 }
@@ -33,22 +54,38 @@ impl TryFrom<&libfindora::Transaction> for Transaction {
     type Error = abcf::Error;
 
     fn try_from(tx: &libfindora::Transaction) -> Result<Self, Self::Error> {
+        let signers = tx.verify()?;
+
         let mut inputs = Vec::new();
+        let mut unlocks = BTreeMap::new();
 
         for input in &tx.inputs {
-            if let libfindora::InputOperation::TransferAsset = input.operation {
-                let txid = if input.txid == H512::zero() {
-                    tx.txid
-                } else {
-                    input.txid
-                };
-                inputs.push(OutputId { txid, n: input.n })
+            match &input.operation {
+                libfindora::InputOperation::TransferAsset => {
+                    let txid = if input.txid == H512::zero() {
+                        tx.txid
+                    } else {
+                        input.txid
+                    };
+                    inputs.push(OutputId { txid, n: input.n })
+                }
+                libfindora::InputOperation::HashUnlock(unlock) => {
+                    let output_id = OutputId {
+                        txid: input.txid,
+                        n: input.n,
+                    };
+                    unlocks.insert(output_id.clone(), unlock.clone());
+                    inputs.push(output_id);
+                }
+                _ => {}
             }
         }
 
         let mut outputs = Vec::new();
+        let mut minted = BTreeMap::new();
+        let mut hash_locks = BTreeMap::new();
 
-        for output in &tx.outputs {
+        for (index, output) in tx.outputs.iter().enumerate() {
             match output.operation {
                 libfindora::OutputOperation::TransferAsset => outputs.push(output.core.clone()),
                 libfindora::OutputOperation::Fee => outputs.push(output.core.clone()),
@@ -60,6 +97,24 @@ impl TryFrom<&libfindora::Transaction> for Transaction {
                     output_new.address = Address::blockhole();
                     outputs.push(output_new);
                 }
+                libfindora::OutputOperation::Burn => {
+                    // Burned output is kept for balance, but nobody can spend it.
+                    let mut output_new = output.core.clone();
+                    output_new.address = Address::blockhole();
+                    outputs.push(output_new);
+                }
+                libfindora::OutputOperation::HashLock(lock) => {
+                    hash_locks.insert(outputs.len(), lock.clone());
+                    outputs.push(output.core.clone());
+                }
+                libfindora::OutputOperation::IssueAsset
+                | libfindora::OutputOperation::IssueAssetWithProof(_) => {
+                    let output_id = OutputId {
+                        txid: tx.txid,
+                        n: index.try_into().map_err(crate::Error::from)?,
+                    };
+                    minted.insert(output_id, output.core.clone());
+                }
                 _ => {}
             }
         }
@@ -69,6 +124,10 @@ impl TryFrom<&libfindora::Transaction> for Transaction {
             inputs,
             outputs,
             proof: tx.proof.clone(),
+            signers,
+            minted,
+            unlocks,
+            hash_locks,
         })
     }
 }
@@ -1,13 +1,35 @@
 use crate::{Error, Result, Transaction, ValidateTransaction};
-use abcf::bs3::MapStore;
-use libfindora::utxo::{Output, OutputId};
+use abcf::bs3::{MapStore, ValueStore};
+use libfindora::{
+    htlc::HashLock,
+    utxo::{Output, OutputId},
+};
 use rand_chacha::ChaChaRng;
 use zei::setup::PublicParams;
 
+/// Height and time of current block, zero before first block.
+pub fn last_block(last_block: &impl ValueStore<(i64, i64)>) -> Result<(i64, i64)> {
+    Ok(last_block.get()?.map(|v| *v).unwrap_or_default())
+}
+
+/// Reject spending output locked at `height` and `timestamp`.
+pub fn check_lock(input: &OutputId, record: &Output, height: i64, timestamp: i64) -> Result<()> {
+    if let Some(lock) = &record.lock {
+        if !lock.is_unlocked(height, timestamp) {
+            return Err(Error::LockedOutput(input.clone(), lock.clone()));
+        }
+    }
+
+    Ok(())
+}
+
 pub fn check_tx(
     params: &mut PublicParams,
     prng: &mut ChaChaRng,
     outputs_set: &impl MapStore<OutputId, Output>,
+    hash_locks: &impl MapStore<OutputId, HashLock>,
+    height: i64,
+    timestamp: i64,
     tx: &Transaction,
 ) -> Result<()> {
     let mut validate_tx = ValidateTransaction {
@@ -17,14 +39,35 @@ pub fn check_tx(
     };
 
     for input in &tx.inputs {
-        let record = outputs_set
-            .get(input)?
-            .ok_or_else(|| Error::NoUnspentOutput(input.clone()))?;
-        validate_tx
-            .inputs
-            .push(record.clone().to_blind_asset_record());
+        let record = if let Some(output) = tx.minted.get(input) {
+            output.clone()
+        } else {
+            outputs_set
+                .get(input)?
+                .ok_or_else(|| Error::NoUnspentOutput(input.clone()))?
+                .clone()
+        };
+
+        let lock = hash_locks.get(input)?;
+        let owner = super::unlock(
+            input,
+            &record,
+            lock.as_deref(),
+            tx.unlocks.get(input),
+            height,
+        )?;
+
+        if !tx.signers.contains(&owner) {
+            return Err(Error::MissingSignature(owner, input.clone()));
+        }
+
+        check_lock(input, &record, height, timestamp)?;
+
+        validate_tx.inputs.push(record.to_blind_asset_record());
     }
 
+    super::check_deadlines(&tx.hash_locks, height)?;
+
     for output in &tx.outputs {
         validate_tx
             .outputs
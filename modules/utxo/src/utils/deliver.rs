@@ -4,41 +4,70 @@ use super::{insert_owned_outputs_map, OwnedOutputOperation};
 use crate::{Error, Result, Transaction, ValidateTransaction};
 use abcf::bs3::MapStore;
 use libfindora::{
+    htlc::HashLock,
     utxo::{Output, OutputId},
     Address,
 };
 use rand_chacha::ChaChaRng;
 use zei::setup::PublicParams;
 
+/// Changes of outputs made by transaction.
+#[derive(Debug, Default)]
+pub struct Delivered {
+    pub owned_outputs: BTreeMap<Address, Vec<OwnedOutputOperation>>,
+    /// Consumed outputs with their records.
+    pub spent: Vec<(OutputId, Output)>,
+}
+
 pub fn deliver_tx(
     params: &mut PublicParams,
     prng: &mut ChaChaRng,
     outputs_set: &mut impl MapStore<OutputId, Output>,
+    hash_locks: &mut impl MapStore<OutputId, HashLock>,
+    height: i64,
+    timestamp: i64,
     tx: &Transaction,
-) -> Result<BTreeMap<Address, Vec<OwnedOutputOperation>>> {
+) -> Result<Delivered> {
     let mut validate_tx = ValidateTransaction {
         inputs: Vec::new(),
         outputs: Vec::new(),
         proof: tx.proof.clone(),
     };
 
-    let mut res = BTreeMap::new();
+    let mut res = Delivered::default();
 
     for input in &tx.inputs {
         let record = outputs_set
             .remove(input)?
             .ok_or_else(|| Error::NoUnspentOutput(input.clone()))?;
+
+        let lock = hash_locks.remove(input)?;
+        let owner = super::unlock(input, &record, lock.as_ref(), tx.unlocks.get(input), height)?;
+
+        if !tx.signers.contains(&owner) {
+            return Err(Error::MissingSignature(owner, input.clone()));
+        }
+
+        super::check_lock(input, &record, height, timestamp)?;
+
         validate_tx
             .inputs
             .push(record.clone().to_blind_asset_record());
 
-        insert_owned_outputs_map(
-            &mut res,
-            record.address,
-            OwnedOutputOperation::Del(input.clone()),
-        );
+        // Hash-time-locked output isn't in owned outputs.
+        if lock.is_none() {
+            insert_owned_outputs_map(
+                &mut res.owned_outputs,
+                record.address.clone(),
+                OwnedOutputOperation::Del(input.clone()),
+            );
+        }
+
+        res.spent.push((input.clone(), record));
     }
 
+    super::check_deadlines(&tx.hash_locks, height)?;
+
     for output in &tx.outputs {
         validate_tx
             .outputs
@@ -58,10 +87,15 @@ pub fn deliver_tx(
 
         outputs_set.insert(output_id.clone(), output.clone())?;
 
+        if let Some(lock) = tx.hash_locks.get(&i) {
+            hash_locks.insert(output_id, lock.clone())?;
+            continue;
+        }
+
         let owner = output.address.clone();
 
         insert_owned_outputs_map(
-            &mut res,
+            &mut res.owned_outputs,
             owner,
             OwnedOutputOperation::Add(output_id.clone()),
         );
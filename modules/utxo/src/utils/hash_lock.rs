@@ -0,0 +1,56 @@
+use std::collections::BTreeMap;
+
+use libfindora::{
+    htlc::{HashLock, Unlock},
+    utxo::{Output, OutputId},
+    Address,
+};
+
+use crate::{Error, Result};
+
+/// Reject hash-time-lock which already expired when created, it can only be refunded.
+pub fn check_deadlines(hash_locks: &BTreeMap<usize, HashLock>, height: i64) -> Result<()> {
+    for (index, lock) in hash_locks {
+        if lock.is_expired(height) {
+            return Err(Error::HashLockDeadlinePassed(*index, lock.deadline));
+        }
+    }
+
+    Ok(())
+}
+
+/// Check spend condition of input, return the address must sign for it.
+///
+/// Hash-time-locked output can only be spent by `Unlock`, and `Unlock` can
+/// only spend hash-time-locked output.
+pub fn unlock(
+    input: &OutputId,
+    record: &Output,
+    lock: Option<&HashLock>,
+    unlock: Option<&Unlock>,
+    height: i64,
+) -> Result<Address> {
+    match (lock, unlock) {
+        (None, None) => Ok(record.address.clone()),
+        (Some(lock), Some(Unlock::Claim(preimage))) => {
+            if lock.is_expired(height) {
+                return Err(Error::HashLockExpired(input.clone(), lock.deadline));
+            }
+
+            if !lock.is_preimage(preimage) {
+                return Err(Error::HashLockPreimageMismatch(input.clone()));
+            }
+
+            Ok(record.address.clone())
+        }
+        (Some(lock), Some(Unlock::Refund)) => {
+            if !lock.is_expired(height) {
+                return Err(Error::HashLockNotExpired(input.clone(), lock.deadline));
+            }
+
+            Ok(lock.sender.clone())
+        }
+        (Some(_), None) => Err(Error::HashLockRequireUnlock(input.clone())),
+        (None, Some(_)) => Err(Error::NotHashLock(input.clone())),
+    }
+}
@@ -1,11 +1,20 @@
 mod check;
-pub use check::check_tx;
+pub use check::{check_lock, check_tx, last_block};
 
 mod operation;
 pub use operation::*;
 
 mod deliver;
-pub use deliver::deliver_tx;
+pub use deliver::{deliver_tx, Delivered};
+
+mod pending;
+pub use pending::*;
+
+mod hash_lock;
+pub use hash_lock::{check_deadlines, unlock};
+
+mod owned;
+pub use owned::*;
 
 mod mint;
 pub use mint::mint;
@@ -1,6 +1,5 @@
 use std::collections::BTreeMap;
 
-use crate::{Error, Result};
 use libfindora::{utxo::OutputId, Address};
 
 pub enum OwnedOutputOperation {
@@ -8,39 +7,6 @@ pub enum OwnedOutputOperation {
     Del(OutputId),
 }
 
-fn get_index(array: &[OutputId], target: &OutputId) -> Option<usize> {
-    array
-        .iter()
-        .position(|x| x.txid == target.txid && x.n == target.n)
-}
-
-pub fn insert_by_operation(
-    target: &mut Vec<OutputId>,
-    ops: Vec<OwnedOutputOperation>,
-) -> Result<()> {
-    for op in ops {
-        match op {
-            OwnedOutputOperation::Add(v) => {
-                // if v already in target, failed.
-                if get_index(target, &v).is_none() {
-                    target.push(v);
-                } else {
-                    return Err(Error::DuplicateOutput(v));
-                }
-            }
-            OwnedOutputOperation::Del(v) => {
-                if let Some(index) = get_index(target, &v) {
-                    target.remove(index);
-                } else {
-                    return Err(Error::MissingOutput(v));
-                }
-            }
-        }
-    }
-
-    Ok(())
-}
-
 pub fn insert_owned_outputs_map(
     map: &mut BTreeMap<Address, Vec<OwnedOutputOperation>>,
     owner: Address,
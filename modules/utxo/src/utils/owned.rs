@@ -0,0 +1,229 @@
+use abcf::bs3::MapStore;
+use libfindora::{
+    utxo::{Output, OutputId},
+    Address,
+};
+
+use super::OwnedOutputOperation;
+use crate::{
+    rpc::{OwnedOutput, OwnedOutputsRequest, OwnedOutputsResponse, MAX_OWNED_OUTPUTS_LIMIT},
+    Error, Result,
+};
+
+/// Outputs owned by address.
+///
+/// Outputs of an address are stored in slots `0..count`, removed output is
+/// replaced by the last one, so insert and remove are O(log n) and slots can
+/// be read by page.
+pub trait OwnedOutputStore {
+    /// Number of outputs owned by address.
+    fn owned_count(&self, address: &Address) -> Result<u64>;
+
+    /// Outputs owned by address in slots `start..start + limit`.
+    fn owned_outputs(&self, address: &Address, start: u64, limit: u64) -> Result<Vec<OutputId>>;
+
+    fn insert_owned(&mut self, address: &Address, output_id: OutputId) -> Result<()>;
+
+    fn remove_owned(&mut self, address: &Address, output_id: &OutputId) -> Result<()>;
+
+    fn apply_owned(&mut self, address: &Address, ops: Vec<OwnedOutputOperation>) -> Result<()> {
+        for op in ops {
+            match op {
+                OwnedOutputOperation::Add(v) => self.insert_owned(address, v)?,
+                OwnedOutputOperation::Del(v) => self.remove_owned(address, &v)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Stores of utxo module's owned outputs index.
+///
+/// `legacy` is the old `Vec<OutputId>` layout, it's moved into index the first
+/// time the address is written.
+pub struct OwnedOutputs<'a, O, C, I, L> {
+    pub outputs: &'a mut O,
+    pub counts: &'a mut C,
+    pub index: &'a mut I,
+    pub legacy: &'a mut L,
+}
+
+impl<'a, O, C, I, L> OwnedOutputs<'a, O, C, I, L>
+where
+    O: MapStore<(Address, u64), OutputId>,
+    C: MapStore<Address, u64>,
+    I: MapStore<(Address, OutputId), u64>,
+    L: MapStore<Address, Vec<OutputId>>,
+{
+    fn push(&mut self, address: &Address, output_id: OutputId) -> Result<()> {
+        let key = (address.clone(), output_id.clone());
+
+        if self.index.get(&key)?.is_some() {
+            return Err(Error::DuplicateOutput(output_id));
+        }
+
+        let count = owned_count(self.counts, self.legacy, address)?;
+
+        self.outputs.insert((address.clone(), count), output_id)?;
+        self.index.insert(key, count)?;
+        self.counts.insert(address.clone(), count + 1)?;
+
+        Ok(())
+    }
+
+    /// Move outputs of address from legacy layout into index.
+    pub fn migrate(&mut self, address: &Address) -> Result<()> {
+        if let Some(ids) = self.legacy.remove(address)? {
+            for output_id in ids {
+                self.push(address, output_id)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a, O, C, I, L> OwnedOutputStore for OwnedOutputs<'a, O, C, I, L>
+where
+    O: MapStore<(Address, u64), OutputId>,
+    C: MapStore<Address, u64>,
+    I: MapStore<(Address, OutputId), u64>,
+    L: MapStore<Address, Vec<OutputId>>,
+{
+    fn owned_count(&self, address: &Address) -> Result<u64> {
+        owned_count(self.counts, self.legacy, address)
+    }
+
+    fn owned_outputs(&self, address: &Address, start: u64, limit: u64) -> Result<Vec<OutputId>> {
+        owned_outputs(
+            self.outputs,
+            self.counts,
+            self.legacy,
+            address,
+            start,
+            limit,
+        )
+    }
+
+    fn insert_owned(&mut self, address: &Address, output_id: OutputId) -> Result<()> {
+        self.migrate(address)?;
+        self.push(address, output_id)
+    }
+
+    fn remove_owned(&mut self, address: &Address, output_id: &OutputId) -> Result<()> {
+        self.migrate(address)?;
+
+        let slot = self
+            .index
+            .remove(&(address.clone(), output_id.clone()))?
+            .ok_or_else(|| Error::MissingOutput(output_id.clone()))?;
+
+        let last = owned_count(self.counts, self.legacy, address)?
+            .checked_sub(1)
+            .ok_or_else(|| Error::MissingOutput(output_id.clone()))?;
+
+        let moved = self
+            .outputs
+            .remove(&(address.clone(), last))?
+            .ok_or_else(|| Error::MissingOutput(output_id.clone()))?;
+
+        if slot != last {
+            self.outputs
+                .insert((address.clone(), slot), moved.clone())?;
+            self.index.insert((address.clone(), moved), slot)?;
+        }
+
+        if last == 0 {
+            self.counts.remove(address)?;
+        } else {
+            self.counts.insert(address.clone(), last)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Number of outputs owned by address, include outputs not migrated.
+pub fn owned_count(
+    counts: &impl MapStore<Address, u64>,
+    legacy: &impl MapStore<Address, Vec<OutputId>>,
+    address: &Address,
+) -> Result<u64> {
+    let count = counts.get(address)?.map(|c| *c).unwrap_or_default();
+
+    let legacy_count = match legacy.get(address)? {
+        Some(ids) => ids.len().try_into()?,
+        None => 0,
+    };
+
+    Ok(count + legacy_count)
+}
+
+/// Outputs owned by address in slots `start..start + limit`.
+///
+/// Outputs not migrated are seen as following the indexed ones.
+pub fn owned_outputs(
+    outputs: &impl MapStore<(Address, u64), OutputId>,
+    counts: &impl MapStore<Address, u64>,
+    legacy: &impl MapStore<Address, Vec<OutputId>>,
+    address: &Address,
+    start: u64,
+    limit: u64,
+) -> Result<Vec<OutputId>> {
+    let count = counts.get(address)?.map(|c| *c).unwrap_or_default();
+    let total = owned_count(counts, legacy, address)?;
+    let end = start.saturating_add(limit).min(total);
+
+    let mut res = Vec::new();
+
+    for slot in start..end.min(count) {
+        if let Some(output_id) = outputs.get(&(address.clone(), slot))? {
+            res.push(output_id.clone());
+        }
+    }
+
+    if end > count {
+        if let Some(ids) = legacy.get(address)? {
+            let from: usize = start.saturating_sub(count).try_into()?;
+            let to: usize = (end - count).try_into()?;
+
+            for output_id in ids.iter().take(to).skip(from) {
+                res.push(output_id.clone());
+            }
+        }
+    }
+
+    Ok(res)
+}
+
+/// Scan a page of outputs owned by address with filters.
+pub fn query_owned_outputs(
+    outputs_set: &impl MapStore<OutputId, Output>,
+    outputs: &impl MapStore<(Address, u64), OutputId>,
+    counts: &impl MapStore<Address, u64>,
+    legacy: &impl MapStore<Address, Vec<OutputId>>,
+    req: &OwnedOutputsRequest,
+) -> Result<OwnedOutputsResponse> {
+    // Zero limit never moves cursor forward.
+    let limit = req.limit.clamp(1, MAX_OWNED_OUTPUTS_LIMIT);
+    let total = owned_count(counts, legacy, &req.address)?;
+
+    let mut res = OwnedOutputsResponse::default();
+
+    for output_id in owned_outputs(outputs, counts, legacy, &req.address, req.cursor, limit)? {
+        if let Some(output) = outputs_set.get(&output_id)? {
+            if req.is_match(&output) {
+                res.outputs.push(OwnedOutput {
+                    output_id,
+                    output: output.clone(),
+                });
+            }
+        }
+    }
+
+    let next = req.cursor.saturating_add(limit);
+    if next < total {
+        res.next_cursor = Some(next);
+    }
+
+    Ok(res)
+}
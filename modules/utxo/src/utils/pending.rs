@@ -0,0 +1,35 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use libfindora::utxo::OutputId;
+use primitive_types::H512;
+
+use crate::{Error, Result, Transaction};
+
+/// Record inputs of a mempool transaction, reject it if any input is already
+/// spent by another pending transaction.
+///
+/// Recheck of the same transaction is allowed.
+pub fn insert_pending_inputs(
+    pending_inputs: &mut BTreeMap<OutputId, H512>,
+    tx: &Transaction,
+) -> Result<()> {
+    let mut inputs = BTreeSet::new();
+
+    for input in &tx.inputs {
+        if !inputs.insert(input) {
+            return Err(Error::DuplicateInput(input.clone()));
+        }
+
+        if let Some(txid) = pending_inputs.get(input) {
+            if txid != &tx.txid {
+                return Err(Error::PendingSpent(input.clone(), *txid));
+            }
+        }
+    }
+
+    for input in &tx.inputs {
+        pending_inputs.insert(input.clone(), tx.txid);
+    }
+
+    Ok(())
+}